@@ -294,7 +294,7 @@ echo ""
 echo -e "${BLUE}🔗 Verification Links:${NC}"
 echo -e "   📊 DaaS API: http://localhost:8081"
 echo -e "   🌐 Sui Explorer: https://testnet.suivision.xyz"
-echo -e "   📋 K8s API: http://localhost:6444"
+echo -e "   📋 K8s API: http://localhost:8081"
 echo ""
 
 if [ "$TX_HASH" != "simulation" ] && [ "$TX_HASH" != "null" ]; then
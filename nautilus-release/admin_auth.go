@@ -0,0 +1,159 @@
+// Admin Auth - 관리자 API 토큰별 신원 및 권한 범위
+package main
+
+import (
+	"crypto/subtle"
+	"encoding/csv"
+	"fmt"
+	"net/http"
+	"os"
+	"strings"
+
+	"github.com/sirupsen/logrus"
+)
+
+// 관리자 권한 범위
+const (
+	adminScopeRead        = "read"        // 상태/지표/요약 조회
+	adminScopeMaintenance = "maintenance" // 유지보수 모드 변경
+	adminScopeSnapshot    = "snapshot"    // etcd 스냅샷 생성
+)
+
+// adminScopes - 유효한 권한 범위 전체
+var adminScopes = []string{adminScopeRead, adminScopeMaintenance, adminScopeSnapshot}
+
+// adminToken - 관리자 토큰 1개의 신원과 권한 범위
+type adminToken struct {
+	token  string
+	name   string
+	scopes map[string]bool
+}
+
+// AdminAuth - 관리자 API bearer 토큰 인가
+type AdminAuth struct {
+	logger *logrus.Logger
+	tokens []adminToken
+}
+
+// NewAdminAuth - NAUTILUS_ADMIN_TOKEN(신원 "admin", 모든 권한)과 NAUTILUS_ADMIN_TOKENS_FILE 로드
+// (토큰이 하나도 없으면 관리자 API 비활성화)
+func NewAdminAuth(logger *logrus.Logger) *AdminAuth {
+	auth := &AdminAuth{logger: logger}
+
+	if token := getEnvOrDefault("NAUTILUS_ADMIN_TOKEN", ""); token != "" {
+		auth.tokens = append(auth.tokens, adminToken{token: token, name: "admin", scopes: allAdminScopes()})
+	}
+
+	if path := getEnvOrDefault("NAUTILUS_ADMIN_TOKENS_FILE", ""); path != "" {
+		tokens, err := loadAdminTokens(path)
+		if err != nil {
+			// 잘못된 파일의 일부 토큰만 적용하지 않도록 파일 전체를 무시
+			logger.Errorf("❌ %v, ignoring admin tokens file", err)
+		} else {
+			auth.tokens = append(auth.tokens, tokens...)
+			logger.Infof("🔑 Loaded %d admin tokens from %s", len(tokens), path)
+		}
+	}
+	return auth
+}
+
+// loadAdminTokens - "token,name,scope1|scope2" 형식 CSV 로드 (#으로 시작하는 줄은 주석)
+func loadAdminTokens(path string) ([]adminToken, error) {
+	file, err := os.Open(path)
+	if err != nil {
+		return nil, fmt.Errorf("failed to read admin tokens: %v", err)
+	}
+	defer file.Close()
+
+	reader := csv.NewReader(file)
+	reader.Comment = '#'
+	reader.FieldsPerRecord = 3
+	reader.TrimLeadingSpace = true
+	records, err := reader.ReadAll()
+	if err != nil {
+		return nil, fmt.Errorf("failed to parse admin tokens: %v", err)
+	}
+
+	seen := make(map[string]bool)
+	tokens := make([]adminToken, 0, len(records))
+	for i, record := range records {
+		token, name := strings.TrimSpace(record[0]), strings.TrimSpace(record[1])
+		if token == "" || name == "" {
+			return nil, fmt.Errorf("admin tokens line %d: token and name are required", i+1)
+		}
+		if seen[token] {
+			return nil, fmt.Errorf("admin tokens line %d: duplicate token for %s", i+1, name)
+		}
+		seen[token] = true
+
+		scopes := make(map[string]bool)
+		for _, scope := range strings.Split(record[2], "|") {
+			scope = strings.TrimSpace(scope)
+			if !isAdminScope(scope) {
+				return nil, fmt.Errorf("admin tokens line %d: invalid scope %q for %s", i+1, scope, name)
+			}
+			scopes[scope] = true
+		}
+		tokens = append(tokens, adminToken{token: token, name: name, scopes: scopes})
+	}
+	return tokens, nil
+}
+
+// Authorize - bearer 토큰의 신원과 권한 범위 확인 (허용 시 신원 이름 반환)
+// 평문 HTTP 요청은 토큰이 노출되므로 토큰과 무관하게 거절
+func (a *AdminAuth) Authorize(r *http.Request, scope string) (string, bool) {
+	bearer, found := strings.CutPrefix(r.Header.Get("Authorization"), "Bearer ")
+	if !found || bearer == "" {
+		return "", false
+	}
+	if r.TLS == nil {
+		a.logger.Warnf("🔒 Rejected admin request over plain HTTP: %s %s from %s", r.Method, r.URL.Path, r.RemoteAddr)
+		return "", false
+	}
+
+	// 일치 여부가 응답 시간으로 드러나지 않도록 모든 토큰과 상수 시간 비교
+	var match *adminToken
+	for i := range a.tokens {
+		if subtle.ConstantTimeCompare([]byte(bearer), []byte(a.tokens[i].token)) == 1 {
+			match = &a.tokens[i]
+		}
+	}
+	if match == nil {
+		return "", false
+	}
+	if !match.scopes[scope] {
+		a.logger.Warnf("🔒 Admin %s lacks %s scope for %s %s", match.name, scope, r.Method, r.URL.Path)
+		return match.name, false
+	}
+	return match.name, true
+}
+
+// authorizeAdmin - 관리자 API 요청 인가 (신원, 허용 여부)
+func (a *APIServer) authorizeAdmin(r *http.Request, scope string) (string, bool) {
+	return a.adminAuth.Authorize(r, scope)
+}
+
+// isAdminRequest - 읽기 권한이 있는 관리자 토큰인지 확인
+func (a *APIServer) isAdminRequest(r *http.Request) bool {
+	_, ok := a.authorizeAdmin(r, adminScopeRead)
+	return ok
+}
+
+// isAdminScope - 유효한 권한 범위인지 확인
+func isAdminScope(scope string) bool {
+	for _, valid := range adminScopes {
+		if scope == valid {
+			return true
+		}
+	}
+	return false
+}
+
+// allAdminScopes - 모든 권한 범위 집합
+func allAdminScopes() map[string]bool {
+	scopes := make(map[string]bool, len(adminScopes))
+	for _, scope := range adminScopes {
+		scopes[scope] = true
+	}
+	return scopes
+}
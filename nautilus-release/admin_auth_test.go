@@ -0,0 +1,83 @@
+package main
+
+import (
+	"net/http/httptest"
+	"os"
+	"path/filepath"
+	"testing"
+
+	"github.com/sirupsen/logrus"
+)
+
+func TestLoadAdminTokens(t *testing.T) {
+	tests := []struct {
+		name    string
+		content string
+		count   int
+		wantErr bool
+	}{
+		{"empty", "", 0, false},
+		{"comment", "# token,name,scopes\n", 0, false},
+		{"single", "tok-1,ci,read\n", 1, false},
+		{"multiple scopes", "tok-1,ops,read|maintenance|snapshot\ntok-2,ci,read\n", 2, false},
+		{"missing field", "tok-1,ci\n", 0, true},
+		{"empty name", "tok-1,,read\n", 0, true},
+		{"unknown scope", "tok-1,ci,read|delete\n", 0, true},
+		{"empty scope", "tok-1,ci,\n", 0, true},
+		{"duplicate token", "tok-1,ci,read\ntok-1,ops,snapshot\n", 0, true},
+	}
+
+	for _, tt := range tests {
+		path := filepath.Join(t.TempDir(), "tokens.csv")
+		if err := os.WriteFile(path, []byte(tt.content), 0600); err != nil {
+			t.Fatal(err)
+		}
+		tokens, err := loadAdminTokens(path)
+		if (err != nil) != tt.wantErr || len(tokens) != tt.count {
+			t.Errorf("%s: loadAdminTokens() = %d tokens, %v; want %d tokens, error %v",
+				tt.name, len(tokens), err, tt.count, tt.wantErr)
+		}
+	}
+}
+
+func TestAdminAuthAuthorize(t *testing.T) {
+	auth := &AdminAuth{
+		logger: logrus.New(),
+		tokens: []adminToken{
+			{token: "root-token", name: "admin", scopes: allAdminScopes()},
+			{token: "ci-token", name: "ci", scopes: map[string]bool{adminScopeRead: true}},
+		},
+	}
+
+	tests := []struct {
+		header   string
+		scope    string
+		wantName string
+		wantOK   bool
+	}{
+		{"Bearer root-token", adminScopeSnapshot, "admin", true},
+		{"Bearer ci-token", adminScopeRead, "ci", true},
+		{"Bearer ci-token", adminScopeMaintenance, "ci", false},
+		{"Bearer unknown", adminScopeRead, "", false},
+		{"ci-token", adminScopeRead, "", false},
+		{"Bearer ", adminScopeRead, "", false},
+		{"", adminScopeRead, "", false},
+	}
+
+	for _, tt := range tests {
+		r := httptest.NewRequest("GET", "https://nautilus.local/api/admin/status", nil)
+		if tt.header != "" {
+			r.Header.Set("Authorization", tt.header)
+		}
+		name, ok := auth.Authorize(r, tt.scope)
+		if name != tt.wantName || ok != tt.wantOK {
+			t.Errorf("Authorize(%q, %s) = %q, %v; want %q, %v", tt.header, tt.scope, name, ok, tt.wantName, tt.wantOK)
+		}
+	}
+
+	plain := httptest.NewRequest("GET", "http://nautilus.local/api/admin/status", nil)
+	plain.Header.Set("Authorization", "Bearer root-token")
+	if _, ok := auth.Authorize(plain, adminScopeRead); ok {
+		t.Error("Authorize() accepted a valid token over plain HTTP")
+	}
+}
@@ -0,0 +1,51 @@
+// Admin Status - kubectl 없이 조회하는 마스터 상태 관리자 API
+package main
+
+import (
+	"encoding/json"
+	"net/http"
+	"time"
+)
+
+// AdminStatus - 마스터 구성요소 상태
+type AdminStatus struct {
+	K3sPhase       string            `json:"k3s_phase"`
+	K3sReady       bool              `json:"k3s_ready"`
+	Maintenance    MaintenanceStatus `json:"maintenance"`
+	MemoryPressure string            `json:"memory_pressure"`
+	ClockAnchored  bool              `json:"clock_anchored"`
+	TrustedTime    string            `json:"trusted_time"`
+	AuditEnabled   bool              `json:"audit_enabled"`
+}
+
+// handleAdminStatus - 마스터 상태 조회 관리자 API (GET)
+func (a *APIServer) handleAdminStatus(w http.ResponseWriter, r *http.Request) {
+	if !a.isAdminRequest(r) {
+		http.Error(w, "Forbidden", http.StatusForbidden)
+		return
+	}
+	if r.Method != http.MethodGet {
+		http.Error(w, "Method not allowed", http.StatusMethodNotAllowed)
+		return
+	}
+
+	ready := false
+	select {
+	case <-a.k3sMgr.Ready():
+		ready = true
+	default:
+	}
+
+	status := AdminStatus{
+		K3sPhase:       a.k3sMgr.StartupPhase(),
+		K3sReady:       ready,
+		Maintenance:    a.k3sMgr.maintenance.Status(),
+		MemoryPressure: a.memMonitor.Level().String(),
+		ClockAnchored:  a.k3sMgr.trustedClock.IsAnchored(),
+		TrustedTime:    a.k3sMgr.trustedClock.Now().UTC().Format(time.RFC3339),
+		AuditEnabled:   a.audit.Enabled(),
+	}
+
+	w.Header().Set("Content-Type", "application/json")
+	json.NewEncoder(w).Encode(status)
+}
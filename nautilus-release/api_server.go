@@ -8,22 +8,58 @@ import (
 	"net/http"
 	"net/http/httputil"
 	"net/url"
+	"sync/atomic"
 
 	"github.com/sirupsen/logrus"
 )
 
 // APIServer - HTTP API 서버
 type APIServer struct {
-	logger    *logrus.Logger
-	k3sMgr    *K3sManager
-	server    *http.Server
+	logger      *logrus.Logger
+	k3sMgr      *K3sManager
+	server      *http.Server
+	secretGuard *SecretAccessGuard
+	memMonitor  *MemoryPressureMonitor
+	sizeLimiter *RequestSizeLimiter
+	respGuard   *ResponseGuard
+	etags       *ETagHandler
+	cors        *CORSHandler
+	churn       *ChurnThrottle
+	idempotency *IdempotencyCache
+	frontProxy  *FrontProxyAuth
+	adminAuth   *AdminAuth
+	audit       *AuditLogger
+	metrics     *APIMetrics
+	tlsCertFile string
+	tlsKeyFile  string
+	certs       *CertReloader
+
+	shuttingDown atomic.Bool
 }
 
 // NewAPIServer - 새 API 서버 생성
 func NewAPIServer(logger *logrus.Logger, k3sMgr *K3sManager) *APIServer {
+	// break-glass 이벤트도 감사 로그로 전달
+	audit := NewAuditLogger(logger)
+	k3sMgr.maintenance.SetAuditLogger(audit)
+
 	return &APIServer{
-		logger: logger,
-		k3sMgr: k3sMgr,
+		logger:      logger,
+		k3sMgr:      k3sMgr,
+		secretGuard: NewSecretAccessGuard(logger, audit),
+		memMonitor:  NewMemoryPressureMonitor(logger),
+		sizeLimiter: NewRequestSizeLimiter(logger),
+		respGuard:   NewResponseGuard(logger),
+		etags:       NewETagHandler(logger),
+		cors:        NewCORSHandler(logger),
+		churn:       NewChurnThrottle(logger),
+		idempotency: NewIdempotencyCache(logger),
+		frontProxy:  NewFrontProxyAuth(logger, k3sMgr.dataDir),
+		adminAuth:   NewAdminAuth(logger),
+		audit:       audit,
+		metrics:     NewAPIMetrics(),
+		tlsCertFile: getEnvOrDefault("NAUTILUS_TLS_CERT_FILE", ""),
+		tlsKeyFile:  getEnvOrDefault("NAUTILUS_TLS_KEY_FILE", ""),
 	}
 }
 
@@ -36,6 +72,7 @@ func (a *APIServer) Start(ctx context.Context) {
 	// 헬스체크 엔드포인트
 	mux.HandleFunc("/healthz", a.handleHealth)
 	mux.HandleFunc("/readyz", a.handleReady)
+	mux.HandleFunc("/livez", a.handleLive)
 
 	// 노드 관리 API
 	mux.HandleFunc("/api/v1/nodes/register", a.handleNodeRegister)
@@ -46,18 +83,77 @@ func (a *APIServer) Start(ctx context.Context) {
 	mux.HandleFunc("/api/contract/call", a.handleContractCall)
 	mux.HandleFunc("/api/transactions/history", a.handleTransactionHistory)
 
-	// K8s API 프록시 (포트 6443으로 포워딩)
-	mux.Handle("/api/", a.createK8sProxy())
-	mux.Handle("/apis/", a.createK8sProxy())
+	// 대시보드 요약 API (관리자 read 권한 필요)
+	mux.HandleFunc("/api/summary", a.handleSummary)
+
+	// 관리자 API (NAUTILUS_ADMIN_TOKEN 또는 NAUTILUS_ADMIN_TOKENS_FILE의 권한 범위 필요)
+	mux.HandleFunc("/api/admin/maintenance", a.handleMaintenance)
+	mux.HandleFunc("/api/admin/snapshot", a.handleSnapshot)
+	mux.HandleFunc("/api/admin/status", a.handleAdminStatus)
+	mux.HandleFunc("/api/admin/top-offenders", a.handleTopOffenders)
+	mux.HandleFunc("/metrics", a.handleMetrics)
+	mux.HandleFunc("/metrics/apiserver", a.handleAPIServerMetrics)
+
+	// K8s API 프록시 (포트 6443으로 포워딩) - 나중에 감싼 미들웨어가 먼저 실행됨
+	k8sProxy := a.createK8sProxy()
+	k8sProxy = a.etags.Wrap(k8sProxy)
+	k8sProxy = a.secretGuard.Wrap(k8sProxy)
+	k8sProxy = clampWatchTimeout(k8sProxy)
+	k8sProxy = a.churn.Wrap(k8sProxy)
+	k8sProxy = a.idempotency.Wrap(k8sProxy)
+	k8sProxy = a.k3sMgr.maintenance.Wrap(k8sProxy)
+	k8sProxy = a.memMonitor.Wrap(k8sProxy)
+	k8sProxy = a.sizeLimiter.Wrap(k8sProxy)
+	k8sProxy = a.respGuard.Wrap(k8sProxy)
+	k8sProxy = a.audit.Wrap(k8sProxy)
+	k8sProxy = a.frontProxy.Wrap(k8sProxy)
+	k8sProxy = a.requireK3sReady(k8sProxy)
+	k8sProxy = a.metrics.Wrap(k8sProxy)
+	mux.Handle("/api/", k8sProxy)
+	mux.Handle("/apis/", k8sProxy)
+
+	// API 디스커버리 (kubectl) - 서브트리 패턴만 있으면 301 리다이렉트가 발생하므로 정확한 경로 등록
+	mux.Handle("/api", k8sProxy)
+	mux.Handle("/apis", k8sProxy)
+	mux.Handle("/version", k8sProxy)
+
+	// OpenAPI 스키마 (kubectl explain, 클라이언트 측 검증)
+	mux.Handle("/openapi/", k8sProxy)
+
+	// 메모리 압박 감시, churn/멱등성 캐시 정리, 감사 로그 전송 시작
+	go a.memMonitor.Start(ctx)
+	go a.churn.Start(ctx)
+	go a.idempotency.Start(ctx)
+	go a.audit.Start(ctx)
+	go a.respGuard.Start(ctx)
 
 	a.server = &http.Server{
-		Addr:    ":8080",
-		Handler: mux,
+		Addr:      ":8080",
+		Handler:   a.cors.Wrap(mux),
+		TLSConfig: &tls.Config{MinVersion: tls.VersionTLS12},
+	}
+
+	if err := a.frontProxy.ConfigureServerTLS(a.server.TLSConfig); err != nil {
+		a.logger.Errorf("❌ Failed to configure front proxy auth: %v", err)
+		return
+	}
+
+	if err := a.setupTLS(ctx); err != nil {
+		a.logger.Errorf("❌ Failed to configure TLS: %v", err)
+		return
 	}
 
 	go func() {
-		a.logger.Info("🎯 API Server listening on :8080")
-		if err := a.server.ListenAndServe(); err != nil && err != http.ErrServerClosed {
+		var err error
+		if a.certs != nil {
+			a.logger.Info("🎯 API Server listening on :8080 (TLS)")
+			// 인증서는 TLSConfig.GetCertificate에서 제공
+			err = a.server.ListenAndServeTLS("", "")
+		} else {
+			a.logger.Info("🎯 API Server listening on :8080")
+			err = a.server.ListenAndServe()
+		}
+		if err != nil && err != http.ErrServerClosed {
 			a.logger.Errorf("❌ API Server failed: %v", err)
 		}
 	}()
@@ -65,6 +161,7 @@ func (a *APIServer) Start(ctx context.Context) {
 	// Context 종료 시 서버 정리
 	go func() {
 		<-ctx.Done()
+		a.shuttingDown.Store(true)
 		a.logger.Info("🛑 Shutting down API Server...")
 		a.server.Shutdown(context.Background())
 	}()
@@ -72,21 +169,38 @@ func (a *APIServer) Start(ctx context.Context) {
 	a.logger.Info("✅ API Server started successfully")
 }
 
-// handleHealth - 헬스체크
-func (a *APIServer) handleHealth(w http.ResponseWriter, r *http.Request) {
-	w.WriteHeader(http.StatusOK)
-	fmt.Fprintf(w, "OK")
+// setupTLS - 인증서 파일(변경 시 자동 교체) 또는 자체 서명 인증서로 TLS 구성
+func (a *APIServer) setupTLS(ctx context.Context) error {
+	var err error
+	switch {
+	case a.tlsCertFile != "" && a.tlsKeyFile != "":
+		a.certs, err = NewCertReloader(a.logger, a.tlsCertFile, a.tlsKeyFile)
+	case getEnvOrDefault("NAUTILUS_TLS_SELF_SIGNED", "false") == "true":
+		a.certs, err = NewSelfSignedCertReloader(a.logger)
+	default:
+		return nil
+	}
+	if err != nil {
+		return err
+	}
+
+	a.server.TLSConfig.GetCertificate = a.certs.GetCertificate
+	go a.certs.Start(ctx)
+	return nil
 }
 
-// handleReady - 준비 상태 확인
-func (a *APIServer) handleReady(w http.ResponseWriter, r *http.Request) {
-	if a.k3sMgr.IsRunning() {
-		w.WriteHeader(http.StatusOK)
-		fmt.Fprintf(w, "Ready")
-	} else {
-		w.WriteHeader(http.StatusServiceUnavailable)
-		fmt.Fprintf(w, "Not Ready")
-	}
+// requireK3sReady - K3s가 처음 준비되기 전에는 K8s API 요청을 503으로 거절
+func (a *APIServer) requireK3sReady(next http.Handler) http.Handler {
+	return http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		select {
+		case <-a.k3sMgr.Ready():
+			next.ServeHTTP(w, r)
+		default:
+			w.Header().Set("Retry-After", "5")
+			http.Error(w, fmt.Sprintf("K3s API server is starting (%s)", a.k3sMgr.StartupPhase()),
+				http.StatusServiceUnavailable)
+		}
+	})
 }
 
 // handleNodeRegister - 워커 노드 등록
@@ -153,9 +267,27 @@ func (a *APIServer) createK8sProxy() http.Handler {
 	proxy := httputil.NewSingleHostReverseProxy(target)
 
 	// TLS 검증 비활성화 (개발용)
+	upstreamTLS := &tls.Config{InsecureSkipVerify: true}
+	a.frontProxy.ConfigureUpstreamTLS(upstreamTLS)
 	proxy.Transport = &http.Transport{
-		TLSClientConfig: &tls.Config{InsecureSkipVerify: true},
+		TLSClientConfig: upstreamTLS,
+	}
+
+	// 응답 후처리 (크기 제한 → Secret 마스킹 → ETag 순서로 적용)
+	responseModifiers := []func(*http.Response) error{
+		a.respGuard.ModifyResponse,
+		a.secretGuard.ModifyResponse,
+		a.etags.ModifyResponse,
+	}
+	proxy.ModifyResponse = func(resp *http.Response) error {
+		for _, modify := range responseModifiers {
+			if err := modify(resp); err != nil {
+				return err
+			}
+		}
+		return nil
 	}
+	proxy.ErrorHandler = a.respGuard.HandleError
 
 	return http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
 		a.logger.Debugf("🔄 Proxying K8s API request: %s %s", r.Method, r.URL.Path)
@@ -0,0 +1,92 @@
+// API Server Args - 환경변수로 설정하는 선택적 kube-apiserver 인자
+package main
+
+import (
+	"os"
+	"strings"
+)
+
+// defaultAdmissionPlugins - 기본 활성화 admission 플러그인 (나열 순서는 무시되며 kube-apiserver가 정한 순서로 실행)
+const defaultAdmissionPlugins = "NamespaceLifecycle,LimitRanger,ServiceAccount,DefaultTolerationSeconds,NodeRestriction"
+
+// requiredAdmissionPlugin - 워커 kubelet이 다른 노드의 Node/Pod를 수정하지 못하도록 항상 활성화
+const requiredAdmissionPlugin = "NodeRestriction"
+
+// admissionPluginsArg - NAUTILUS_ADMISSION_PLUGINS (기본값 defaultAdmissionPlugins)에 NodeRestriction이 없으면 추가
+// (enable-admission-plugins는 kube-apiserver 기본 플러그인에 추가로 활성화할 목록이며, 끄려면 disableAdmissionPluginsArg 사용)
+func admissionPluginsArg() string {
+	plugins := splitAdmissionPlugins(getEnvOrDefault("NAUTILUS_ADMISSION_PLUGINS", defaultAdmissionPlugins))
+	if !hasAdmissionPlugin(strings.Join(plugins, ","), requiredAdmissionPlugin) {
+		plugins = append(plugins, requiredAdmissionPlugin)
+	}
+	return strings.Join(plugins, ",")
+}
+
+// disableAdmissionPluginsArg - NAUTILUS_DISABLE_ADMISSION_PLUGINS에서 NodeRestriction을 제외한 비활성화 목록 (없으면 빈 문자열)
+func disableAdmissionPluginsArg() string {
+	var plugins []string
+	for _, plugin := range splitAdmissionPlugins(os.Getenv("NAUTILUS_DISABLE_ADMISSION_PLUGINS")) {
+		if plugin != requiredAdmissionPlugin {
+			plugins = append(plugins, plugin)
+		}
+	}
+	return strings.Join(plugins, ",")
+}
+
+// splitAdmissionPlugins - 쉼표로 구분된 플러그인 목록에서 공백과 빈 항목 제거
+func splitAdmissionPlugins(plugins string) []string {
+	var result []string
+	for _, plugin := range strings.Split(plugins, ",") {
+		if plugin = strings.TrimSpace(plugin); plugin != "" {
+			result = append(result, plugin)
+		}
+	}
+	return result
+}
+
+// hasAdmissionPlugin - 쉼표로 구분된 플러그인 목록에 포함되는지 확인
+func hasAdmissionPlugin(plugins, name string) bool {
+	for _, plugin := range splitAdmissionPlugins(plugins) {
+		if plugin == name {
+			return true
+		}
+	}
+	return false
+}
+
+// apiServerEnvArg - 환경변수 → kube-apiserver 플래그 매핑
+type apiServerEnvArg struct {
+	env  string
+	flag string
+}
+
+// oidcAPIServerArgs - 사람 사용자용 OIDC 인증 (ID 토큰 검증 및 JWKS 캐싱은 kube-apiserver가 수행)
+var oidcAPIServerArgs = []apiServerEnvArg{
+	{"NAUTILUS_OIDC_ISSUER_URL", "oidc-issuer-url"},
+	{"NAUTILUS_OIDC_CLIENT_ID", "oidc-client-id"},
+	{"NAUTILUS_OIDC_CA_FILE", "oidc-ca-file"},
+	{"NAUTILUS_OIDC_USERNAME_CLAIM", "oidc-username-claim"},
+	{"NAUTILUS_OIDC_USERNAME_PREFIX", "oidc-username-prefix"},
+	{"NAUTILUS_OIDC_GROUPS_CLAIM", "oidc-groups-claim"},
+	{"NAUTILUS_OIDC_GROUPS_PREFIX", "oidc-groups-prefix"},
+	{"NAUTILUS_OIDC_REQUIRED_CLAIM", "oidc-required-claim"},
+}
+
+// tokenWebhookAPIServerArgs - 외부 TokenReview 웹훅으로 토큰 검증 위임
+// (설정 파일은 웹훅 URL과 CA 번들을 담은 kubeconfig 형식)
+var tokenWebhookAPIServerArgs = []apiServerEnvArg{
+	{"NAUTILUS_TOKEN_WEBHOOK_CONFIG_FILE", "authentication-token-webhook-config-file"},
+	{"NAUTILUS_TOKEN_WEBHOOK_CACHE_TTL", "authentication-token-webhook-cache-ttl"},
+	{"NAUTILUS_TOKEN_WEBHOOK_VERSION", "authentication-token-webhook-version"},
+}
+
+// envAPIServerArgs - 설정된 환경변수만 --kube-apiserver-arg 인자로 변환
+func envAPIServerArgs(mappings []apiServerEnvArg) []string {
+	var args []string
+	for _, mapping := range mappings {
+		if value := os.Getenv(mapping.env); value != "" {
+			args = append(args, "--kube-apiserver-arg", mapping.flag+"="+value)
+		}
+	}
+	return args
+}
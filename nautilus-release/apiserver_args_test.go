@@ -0,0 +1,45 @@
+package main
+
+import "testing"
+
+func TestAdmissionPluginsArg(t *testing.T) {
+	tests := []struct {
+		env  string
+		want string
+	}{
+		{"", defaultAdmissionPlugins},
+		{"NamespaceLifecycle,NodeRestriction", "NamespaceLifecycle,NodeRestriction"},
+		{"NodeRestriction, LimitRanger", "NodeRestriction,LimitRanger"},
+		{" NamespaceLifecycle ,, LimitRanger ", "NamespaceLifecycle,LimitRanger,NodeRestriction"},
+		{"NamespaceLifecycle,LimitRanger", "NamespaceLifecycle,LimitRanger,NodeRestriction"},
+		{"NamespaceLifecycle,", "NamespaceLifecycle,NodeRestriction"},
+		{"NodeRestrictionX", "NodeRestrictionX,NodeRestriction"},
+	}
+
+	for _, tt := range tests {
+		t.Setenv("NAUTILUS_ADMISSION_PLUGINS", tt.env)
+		if got := admissionPluginsArg(); got != tt.want {
+			t.Errorf("admissionPluginsArg() with %q = %q, want %q", tt.env, got, tt.want)
+		}
+	}
+}
+
+func TestDisableAdmissionPluginsArg(t *testing.T) {
+	tests := []struct {
+		env  string
+		want string
+	}{
+		{"", ""},
+		{"DefaultStorageClass", "DefaultStorageClass"},
+		{" DefaultStorageClass ,, StorageObjectInUseProtection", "DefaultStorageClass,StorageObjectInUseProtection"},
+		{"NodeRestriction", ""},
+		{"DefaultStorageClass,NodeRestriction", "DefaultStorageClass"},
+	}
+
+	for _, tt := range tests {
+		t.Setenv("NAUTILUS_DISABLE_ADMISSION_PLUGINS", tt.env)
+		if got := disableAdmissionPluginsArg(); got != tt.want {
+			t.Errorf("disableAdmissionPluginsArg() with %q = %q, want %q", tt.env, got, tt.want)
+		}
+	}
+}
@@ -0,0 +1,338 @@
+// Audit Logger - 정책 기반 K8s API 감사 로그 (파일/웹훅 백엔드, 비동기 버퍼링)
+package main
+
+import (
+	"bytes"
+	"context"
+	"crypto/rand"
+	"encoding/hex"
+	"encoding/json"
+	"fmt"
+	"net"
+	"net/http"
+	"os"
+	"sync/atomic"
+	"time"
+
+	"github.com/sirupsen/logrus"
+)
+
+// 감사 레벨
+const (
+	auditLevelNone     = "None"
+	auditLevelMetadata = "Metadata"
+)
+
+// auditBufferSize - 백엔드 전송 대기 이벤트 버퍼 크기 (가득 차면 요청 지연 대신 이벤트 폐기)
+const auditBufferSize = 1024
+
+// AuditPolicyRule - 감사 정책 규칙 (빈 목록은 모든 값과 일치)
+type AuditPolicyRule struct {
+	Level      string   `json:"level"`
+	Verbs      []string `json:"verbs"`
+	Resources  []string `json:"resources"`
+	Namespaces []string `json:"namespaces"`
+}
+
+// AuditPolicy - 감사 정책 (첫 번째로 일치하는 규칙 적용)
+type AuditPolicy struct {
+	Rules        []AuditPolicyRule `json:"rules"`
+	DefaultLevel string            `json:"default_level"`
+}
+
+// AuditEvent - 감사 이벤트
+type AuditEvent struct {
+	AuditID      string `json:"audit_id"`
+	Stage        string `json:"stage"`
+	Timestamp    string `json:"timestamp"`
+	User         string `json:"user"`
+	SourceIP     string `json:"source_ip"`
+	Verb         string `json:"verb"`
+	Path         string `json:"path"`
+	Resource     string `json:"resource,omitempty"`
+	Namespace    string `json:"namespace,omitempty"`
+	ResponseCode int    `json:"response_code,omitempty"`
+	LatencyMs    int64  `json:"latency_ms"`
+
+	Annotations map[string]string `json:"annotations,omitempty"`
+}
+
+// AuditLogger - 감사 이벤트 수집 및 백엔드 전송
+type AuditLogger struct {
+	logger       *logrus.Logger
+	policy       *AuditPolicy
+	logPath      string
+	webhookURL   string
+	events       chan *AuditEvent
+	dropped      uint64 // 마지막 경고 이후 폐기 수
+	droppedTotal uint64 // 누적 폐기 수 (/metrics)
+}
+
+// NewAuditLogger - 새 감사 로거 생성 (백엔드가 없으면 비활성화)
+func NewAuditLogger(logger *logrus.Logger) *AuditLogger {
+	policy := &AuditPolicy{DefaultLevel: auditLevelMetadata}
+	if policyFile := getEnvOrDefault("NAUTILUS_AUDIT_POLICY_FILE", ""); policyFile != "" {
+		loaded, err := loadAuditPolicy(policyFile)
+		if err != nil {
+			// 정책 오류 시 모든 요청을 기록 (감사 누락 방지)
+			logger.Errorf("❌ %v, auditing all requests at %s level", err, auditLevelMetadata)
+		} else {
+			policy = loaded
+		}
+	}
+
+	return &AuditLogger{
+		logger:     logger,
+		policy:     policy,
+		logPath:    getEnvOrDefault("NAUTILUS_AUDIT_LOG_PATH", ""),
+		webhookURL: getEnvOrDefault("NAUTILUS_AUDIT_WEBHOOK_URL", ""),
+		events:     make(chan *AuditEvent, auditBufferSize),
+	}
+}
+
+// loadAuditPolicy - JSON 감사 정책 파일 로드
+func loadAuditPolicy(path string) (*AuditPolicy, error) {
+	data, err := os.ReadFile(path)
+	if err != nil {
+		return nil, fmt.Errorf("failed to read audit policy: %v", err)
+	}
+
+	policy := &AuditPolicy{}
+	if err := json.Unmarshal(data, policy); err != nil {
+		return nil, fmt.Errorf("failed to parse audit policy: %v", err)
+	}
+	if policy.DefaultLevel == "" {
+		policy.DefaultLevel = auditLevelMetadata
+	}
+	if !isValidAuditLevel(policy.DefaultLevel) {
+		return nil, fmt.Errorf("invalid audit default_level %q in %s", policy.DefaultLevel, path)
+	}
+	for i, rule := range policy.Rules {
+		if !isValidAuditLevel(rule.Level) {
+			return nil, fmt.Errorf("invalid audit level %q in rule %d of %s", rule.Level, i, path)
+		}
+	}
+	return policy, nil
+}
+
+// isValidAuditLevel - 지원하는 감사 레벨인지 확인 (None, Metadata)
+func isValidAuditLevel(level string) bool {
+	return level == auditLevelNone || level == auditLevelMetadata
+}
+
+// Enabled - 백엔드가 하나 이상 설정되었는지 여부
+func (a *AuditLogger) Enabled() bool {
+	return a.logPath != "" || a.webhookURL != ""
+}
+
+// Start - 버퍼된 이벤트를 백엔드로 전송
+func (a *AuditLogger) Start(ctx context.Context) {
+	if !a.Enabled() {
+		return
+	}
+
+	var file *os.File
+	if a.logPath != "" {
+		var err error
+		file, err = os.OpenFile(a.logPath, os.O_CREATE|os.O_APPEND|os.O_WRONLY, 0600)
+		if err != nil {
+			a.logger.Errorf("❌ Failed to open audit log %s: %v", a.logPath, err)
+		} else {
+			defer file.Close()
+		}
+	}
+
+	a.logger.Info("📝 Audit logging started")
+
+	// 웹훅은 1초 단위로 모아서 전송
+	ticker := time.NewTicker(time.Second)
+	defer ticker.Stop()
+	var batch []*AuditEvent
+
+	for {
+		select {
+		case <-ctx.Done():
+			a.sendWebhook(batch)
+			return
+		case event := <-a.events:
+			if file != nil {
+				line, _ := json.Marshal(event)
+				file.Write(append(line, '\n'))
+			}
+			if a.webhookURL != "" {
+				batch = append(batch, event)
+			}
+		case <-ticker.C:
+			a.sendWebhook(batch)
+			batch = nil
+			if dropped := atomic.SwapUint64(&a.dropped, 0); dropped > 0 {
+				a.logger.Warnf("⚠️ Dropped %d audit events (buffer full)", dropped)
+			}
+		}
+	}
+}
+
+// Wrap - 요청 완료 시 정책에 따라 감사 이벤트 기록
+func (a *AuditLogger) Wrap(next http.Handler) http.Handler {
+	if !a.Enabled() {
+		return next
+	}
+
+	return http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		info, _ := parseK8sPath(r.URL.Path)
+		verb := k8sVerb(r)
+		if a.levelFor(verb, info.Resource, info.Namespace) == auditLevelNone {
+			next.ServeHTTP(w, r)
+			return
+		}
+
+		start := time.Now()
+		recorder := &statusRecorder{ResponseWriter: w, status: http.StatusOK}
+		next.ServeHTTP(recorder, r)
+
+		sourceIP, _, err := net.SplitHostPort(r.RemoteAddr)
+		if err != nil {
+			sourceIP = r.RemoteAddr
+		}
+
+		auditID, err := newAuditID()
+		if err != nil {
+			// ID 생성 실패로 이벤트를 누락하지 않도록 시각 기반 ID 사용
+			a.logger.Errorf("❌ Failed to generate audit ID: %v", err)
+			auditID = fmt.Sprintf("time-%d", start.UnixNano())
+		}
+
+		a.record(&AuditEvent{
+			AuditID:      auditID,
+			Stage:        "ResponseComplete",
+			Timestamp:    start.UTC().Format(time.RFC3339Nano),
+			User:         authenticatedUser(r),
+			SourceIP:     sourceIP,
+			Verb:         verb,
+			Path:         r.URL.Path,
+			Resource:     info.Resource,
+			Namespace:    info.Namespace,
+			ResponseCode: recorder.status,
+			LatencyMs:    time.Since(start).Milliseconds(),
+		})
+	})
+}
+
+// RecordBreakGlass - break-glass 사용 감사 이벤트 (정책 레벨과 무관하게 항상 기록)
+// 감사 백엔드가 없거나 버퍼가 가득 차 기록하지 못하면 false (호출자는 요청을 거절해야 함)
+func (a *AuditLogger) RecordBreakGlass(r *http.Request, kind, reason string) bool {
+	if a == nil || !a.Enabled() {
+		return false
+	}
+
+	auditID, err := newAuditID()
+	if err != nil {
+		a.logger.Errorf("❌ Failed to generate break-glass audit ID: %v", err)
+		return false
+	}
+
+	info, _ := parseK8sPath(r.URL.Path)
+	sourceIP, _, err := net.SplitHostPort(r.RemoteAddr)
+	if err != nil {
+		sourceIP = r.RemoteAddr
+	}
+
+	return a.record(&AuditEvent{
+		AuditID:   auditID,
+		Stage:     "RequestReceived",
+		Timestamp: time.Now().UTC().Format(time.RFC3339Nano),
+		User:      authenticatedUser(r),
+		SourceIP:  sourceIP,
+		Verb:      k8sVerb(r),
+		Path:      r.URL.Path,
+		Resource:  info.Resource,
+		Namespace: info.Namespace,
+		Annotations: map[string]string{
+			"nautilus.io/break-glass":        kind,
+			"nautilus.io/break-glass-reason": reason,
+		},
+	})
+}
+
+// record - 이벤트를 버퍼에 추가 (가득 차면 폐기하여 요청 지연 방지, 폐기 시 false)
+func (a *AuditLogger) record(event *AuditEvent) bool {
+	select {
+	case a.events <- event:
+		return true
+	default:
+		atomic.AddUint64(&a.dropped, 1)
+		atomic.AddUint64(&a.droppedTotal, 1)
+		return false
+	}
+}
+
+// levelFor - 요청에 적용할 감사 레벨
+func (a *AuditLogger) levelFor(verb, resource, namespace string) string {
+	for _, rule := range a.policy.Rules {
+		if matchesAny(rule.Verbs, verb) && matchesAny(rule.Resources, resource) &&
+			matchesAny(rule.Namespaces, namespace) {
+			return rule.Level
+		}
+	}
+	return a.policy.DefaultLevel
+}
+
+// sendWebhook - 이벤트 묶음을 웹훅으로 전송
+func (a *AuditLogger) sendWebhook(batch []*AuditEvent) {
+	if a.webhookURL == "" || len(batch) == 0 {
+		return
+	}
+
+	body, err := json.Marshal(map[string]interface{}{"items": batch})
+	if err != nil {
+		a.logger.Errorf("❌ Failed to encode audit batch: %v", err)
+		return
+	}
+
+	client := &http.Client{Timeout: 5 * time.Second}
+	resp, err := client.Post(a.webhookURL, "application/json", bytes.NewReader(body))
+	if err != nil {
+		a.logger.Errorf("❌ Failed to send %d audit events: %v", len(batch), err)
+		return
+	}
+	resp.Body.Close()
+}
+
+// statusRecorder - 응답 코드 기록용 ResponseWriter (Unwrap으로 Flush/Hijack 지원 유지)
+type statusRecorder struct {
+	http.ResponseWriter
+	status int
+}
+
+// WriteHeader - 응답 코드 기록
+func (s *statusRecorder) WriteHeader(status int) {
+	s.status = status
+	s.ResponseWriter.WriteHeader(status)
+}
+
+// Unwrap - http.ResponseController가 원래 ResponseWriter에 접근하도록 허용
+func (s *statusRecorder) Unwrap() http.ResponseWriter {
+	return s.ResponseWriter
+}
+
+// matchesAny - 빈 목록이거나 값이 목록에 포함되는지 확인
+func matchesAny(values []string, value string) bool {
+	if len(values) == 0 {
+		return true
+	}
+	for _, v := range values {
+		if v == "*" || v == value {
+			return true
+		}
+	}
+	return false
+}
+
+// newAuditID - 감사 이벤트 ID 생성
+func newAuditID() (string, error) {
+	id := make([]byte, 16)
+	if _, err := rand.Read(id); err != nil {
+		return "", err
+	}
+	return hex.EncodeToString(id), nil
+}
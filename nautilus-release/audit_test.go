@@ -0,0 +1,74 @@
+package main
+
+import (
+	"net/http"
+	"net/http/httptest"
+	"os"
+	"path/filepath"
+	"testing"
+
+	"github.com/sirupsen/logrus"
+)
+
+func TestLoadAuditPolicy(t *testing.T) {
+	tests := []struct {
+		name    string
+		policy  string
+		wantErr bool
+	}{
+		{"valid", `{"rules":[{"level":"None","verbs":["watch"]}],"default_level":"Metadata"}`, false},
+		{"default level omitted", `{"rules":[]}`, false},
+		{"invalid default level", `{"default_level":"RequestResponse"}`, true},
+		{"invalid rule level", `{"rules":[{"level":"metadata"}]}`, true},
+		{"malformed", `{"rules":`, true},
+	}
+
+	for _, tt := range tests {
+		path := filepath.Join(t.TempDir(), "policy.json")
+		if err := os.WriteFile(path, []byte(tt.policy), 0600); err != nil {
+			t.Fatal(err)
+		}
+		if _, err := loadAuditPolicy(path); (err != nil) != tt.wantErr {
+			t.Errorf("%s: loadAuditPolicy() error = %v, wantErr %v", tt.name, err, tt.wantErr)
+		}
+	}
+}
+
+func TestAuditPolicyUsesKubernetesVerbs(t *testing.T) {
+	audit := &AuditLogger{
+		logger: logrus.New(),
+		policy: &AuditPolicy{
+			Rules:        []AuditPolicyRule{{Level: auditLevelNone, Verbs: []string{"list", "watch"}}},
+			DefaultLevel: auditLevelMetadata,
+		},
+		logPath: "audit.log",
+		events:  make(chan *AuditEvent, 10),
+	}
+	handler := audit.Wrap(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {}))
+
+	tests := []struct {
+		method   string
+		target   string
+		wantVerb string
+	}{
+		{"GET", "/api/v1/namespaces/default/pods", ""},
+		{"GET", "/api/v1/namespaces/default/pods?watch=true", ""},
+		{"GET", "/api/v1/namespaces/default/pods/web", "get"},
+		{"POST", "/api/v1/namespaces/default/pods", "create"},
+		{"DELETE", "/api/v1/namespaces/default/pods", "deletecollection"},
+	}
+
+	for _, tt := range tests {
+		handler.ServeHTTP(httptest.NewRecorder(), httptest.NewRequest(tt.method, tt.target, nil))
+
+		var gotVerb string
+		select {
+		case event := <-audit.events:
+			gotVerb = event.Verb
+		default:
+		}
+		if gotVerb != tt.wantVerb {
+			t.Errorf("%s %s: audited verb = %q, want %q", tt.method, tt.target, gotVerb, tt.wantVerb)
+		}
+	}
+}
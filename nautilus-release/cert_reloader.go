@@ -0,0 +1,160 @@
+// Cert Reloader - API 서버 TLS 인증서 로드 및 무중단 교체
+package main
+
+import (
+	"context"
+	"crypto/ecdsa"
+	"crypto/elliptic"
+	"crypto/rand"
+	"crypto/tls"
+	"crypto/x509"
+	"crypto/x509/pkix"
+	"fmt"
+	"math/big"
+	"net"
+	"os"
+	"sync"
+	"time"
+
+	"github.com/sirupsen/logrus"
+)
+
+// certReloadInterval - 인증서 파일 변경 확인 주기
+const certReloadInterval = 30 * time.Second
+
+// CertReloader - 인증서 파일 변경 시 새 연결부터 새 인증서 사용
+type CertReloader struct {
+	logger   *logrus.Logger
+	certFile string
+	keyFile  string
+	cert     *tls.Certificate
+	modTime  time.Time
+	mutex    sync.RWMutex
+}
+
+// NewCertReloader - 인증서 파일 기반 리로더 생성
+func NewCertReloader(logger *logrus.Logger, certFile, keyFile string) (*CertReloader, error) {
+	r := &CertReloader{
+		logger:   logger,
+		certFile: certFile,
+		keyFile:  keyFile,
+	}
+	if err := r.reload(); err != nil {
+		return nil, err
+	}
+	return r, nil
+}
+
+// NewSelfSignedCertReloader - 엔클레이브 내부에서 생성한 자체 서명 인증서 사용 (파일 없음)
+func NewSelfSignedCertReloader(logger *logrus.Logger) (*CertReloader, error) {
+	cert, err := generateSelfSignedCert()
+	if err != nil {
+		return nil, err
+	}
+	logger.Info("🔏 Generated in-enclave self-signed TLS certificate")
+	return &CertReloader{
+		logger: logger,
+		cert:   cert,
+	}, nil
+}
+
+// GetCertificate - tls.Config.GetCertificate 콜백
+func (r *CertReloader) GetCertificate(*tls.ClientHelloInfo) (*tls.Certificate, error) {
+	r.mutex.RLock()
+	defer r.mutex.RUnlock()
+	return r.cert, nil
+}
+
+// Start - 주기적으로 인증서 파일 변경 확인
+func (r *CertReloader) Start(ctx context.Context) {
+	if r.certFile == "" {
+		return
+	}
+
+	ticker := time.NewTicker(certReloadInterval)
+	defer ticker.Stop()
+
+	for {
+		select {
+		case <-ctx.Done():
+			return
+		case <-ticker.C:
+			if r.changed() {
+				if err := r.reload(); err != nil {
+					// 교체 실패 시 기존 인증서 유지
+					r.logger.Errorf("❌ Failed to reload TLS certificate, keeping previous: %v", err)
+				}
+			}
+		}
+	}
+}
+
+// changed - 인증서/키 파일 수정 시각이 바뀌었는지 확인
+func (r *CertReloader) changed() bool {
+	r.mutex.RLock()
+	defer r.mutex.RUnlock()
+	return latestModTime(r.certFile, r.keyFile).After(r.modTime)
+}
+
+// reload - 인증서/키 파일 다시 로드
+func (r *CertReloader) reload() error {
+	modTime := latestModTime(r.certFile, r.keyFile)
+	cert, err := tls.LoadX509KeyPair(r.certFile, r.keyFile)
+	if err != nil {
+		return fmt.Errorf("failed to load TLS key pair: %v", err)
+	}
+
+	r.mutex.Lock()
+	r.cert = &cert
+	r.modTime = modTime
+	r.mutex.Unlock()
+
+	r.logger.Infof("🔐 Loaded TLS certificate from %s", r.certFile)
+	return nil
+}
+
+// latestModTime - 여러 파일 중 가장 최근 수정 시각
+func latestModTime(files ...string) time.Time {
+	var latest time.Time
+	for _, file := range files {
+		if info, err := os.Stat(file); err == nil && info.ModTime().After(latest) {
+			latest = info.ModTime()
+		}
+	}
+	return latest
+}
+
+// generateSelfSignedCert - ECDSA P-256 자체 서명 인증서 생성 (유효기간 1년)
+func generateSelfSignedCert() (*tls.Certificate, error) {
+	key, err := ecdsa.GenerateKey(elliptic.P256(), rand.Reader)
+	if err != nil {
+		return nil, fmt.Errorf("failed to generate TLS key: %v", err)
+	}
+
+	serial, err := rand.Int(rand.Reader, new(big.Int).Lsh(big.NewInt(1), 128))
+	if err != nil {
+		return nil, fmt.Errorf("failed to generate serial number: %v", err)
+	}
+
+	now := time.Now()
+	template := &x509.Certificate{
+		SerialNumber: serial,
+		Subject:      pkix.Name{CommonName: "nautilus-control"},
+		NotBefore:    now.Add(-time.Hour),
+		NotAfter:     now.Add(365 * 24 * time.Hour),
+		KeyUsage:     x509.KeyUsageDigitalSignature,
+		ExtKeyUsage:  []x509.ExtKeyUsage{x509.ExtKeyUsageServerAuth},
+		DNSNames:     []string{"nautilus-control", "localhost"},
+		IPAddresses:  []net.IP{net.ParseIP("127.0.0.1")},
+	}
+
+	der, err := x509.CreateCertificate(rand.Reader, template, template, &key.PublicKey, key)
+	if err != nil {
+		return nil, fmt.Errorf("failed to create TLS certificate: %v", err)
+	}
+
+	return &tls.Certificate{
+		Certificate: [][]byte{der},
+		PrivateKey:  key,
+	}, nil
+}
@@ -0,0 +1,176 @@
+// Churn Throttle - 클라이언트별 과도한 쓰기 반복(churn) 감지 및 제한
+package main
+
+import (
+	"context"
+	"crypto/sha256"
+	"encoding/hex"
+	"net"
+	"net/http"
+	"strconv"
+	"strings"
+	"sync"
+	"time"
+
+	"github.com/sirupsen/logrus"
+)
+
+// churnWindow - 쓰기 횟수를 집계하는 구간
+const churnWindow = 10 * time.Second
+
+// churnCounter - (클라이언트, 키) 단위 쓰기 횟수
+type churnCounter struct {
+	windowStart time.Time
+	writes      int
+	warned      bool
+}
+
+// ChurnThrottle - 동일 객체에 대한 클라이언트별 쓰기 빈도 제한
+type ChurnThrottle struct {
+	logger       *logrus.Logger
+	maxWrites    int
+	allowedUsers map[string]bool // 제한하지 않는 인증된 사용자 (authenticatedUser 값)
+	counters     map[string]*churnCounter
+	mutex        sync.Mutex
+}
+
+// NewChurnThrottle - 새 churn 제한기 생성
+func NewChurnThrottle(logger *logrus.Logger) *ChurnThrottle {
+	maxWrites, err := strconv.Atoi(getEnvOrDefault("NAUTILUS_CHURN_MAX_WRITES", "50"))
+	if err != nil || maxWrites <= 0 {
+		maxWrites = 50
+	}
+
+	// User-Agent는 클라이언트가 임의로 설정할 수 있으므로 검증된 사용자명으로만 허용
+	// (k3s 내장 컨트롤러는 6443으로 직접 접속하므로 기본 허용 목록 없음)
+	allowed := make(map[string]bool)
+	for _, user := range strings.Split(getEnvOrDefault("NAUTILUS_CHURN_ALLOWED_USERS", ""), ",") {
+		if user = strings.TrimSpace(user); user != "" {
+			allowed[user] = true
+		}
+	}
+
+	return &ChurnThrottle{
+		logger:       logger,
+		maxWrites:    maxWrites,
+		allowedUsers: allowed,
+		counters:     make(map[string]*churnCounter),
+	}
+}
+
+// Start - 만료된 카운터 주기적 정리
+func (c *ChurnThrottle) Start(ctx context.Context) {
+	ticker := time.NewTicker(time.Minute)
+	defer ticker.Stop()
+
+	for {
+		select {
+		case <-ctx.Done():
+			return
+		case <-ticker.C:
+			c.cleanup()
+		}
+	}
+}
+
+// Wrap - 구간 내 쓰기 횟수가 한도를 넘으면 경고 후 429로 차단
+// (8080 프록시 경유 요청에만 적용, 6443으로 직접 들어오는 요청은 제한하지 않음)
+func (c *ChurnThrottle) Wrap(next http.Handler) http.Handler {
+	return http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		client := authenticatedUser(r)
+		if !isMutatingRequest(r) || isPriorityTraffic(r) || c.allowedUsers[client] {
+			next.ServeHTTP(w, r)
+			return
+		}
+
+		// 메서드와 무관하게 같은 객체에 대한 쓰기를 합산 (PUT/PATCH 번갈아 보내는 우회 방지)
+		key := client + "|" + r.URL.Path
+		writes, warned := c.record(key)
+
+		if writes > c.maxWrites {
+			c.logger.Warnf("🌪️ Throttling write churn from %s on %s (%d writes in %s)",
+				client, r.URL.Path, writes, churnWindow)
+			w.Header().Set("Retry-After", strconv.Itoa(int(churnWindow.Seconds())))
+			http.Error(w, "Too many requests: excessive writes to the same object", http.StatusTooManyRequests)
+			return
+		}
+
+		// 한도의 절반을 넘으면 kubectl/client-go가 표시하는 Warning 헤더로 사전 경고
+		if writes > c.maxWrites/2 {
+			w.Header().Add("Warning", `299 - "high write churn on this object; requests will be throttled"`)
+			if !warned {
+				c.logger.Warnf("⚠️ High write churn from %s on %s", client, r.URL.Path)
+			}
+		}
+
+		next.ServeHTTP(w, r)
+	})
+}
+
+// record - 쓰기 1회 기록 후 구간 내 누적 횟수 반환
+func (c *ChurnThrottle) record(key string) (int, bool) {
+	c.mutex.Lock()
+	defer c.mutex.Unlock()
+
+	now := time.Now()
+	counter, exists := c.counters[key]
+	if !exists || now.Sub(counter.windowStart) > churnWindow {
+		counter = &churnCounter{windowStart: now}
+		c.counters[key] = counter
+	}
+
+	counter.writes++
+	warned := counter.warned
+	if counter.writes > c.maxWrites/2 {
+		counter.warned = true
+	}
+	return counter.writes, warned
+}
+
+// cleanup - 구간이 지난 카운터 삭제
+func (c *ChurnThrottle) cleanup() {
+	c.mutex.Lock()
+	defer c.mutex.Unlock()
+
+	now := time.Now()
+	for key, counter := range c.counters {
+		if now.Sub(counter.windowStart) > churnWindow {
+			delete(c.counters, key)
+		}
+	}
+}
+
+// isMutatingRequest - 쓰기 요청 여부 (dryRun 요청은 저장되지 않으므로 제외)
+func isMutatingRequest(r *http.Request) bool {
+	if isDryRunRequest(r) {
+		return false
+	}
+	switch r.Method {
+	case http.MethodPost, http.MethodPut, http.MethodPatch, http.MethodDelete:
+		return true
+	}
+	return false
+}
+
+// isDryRunRequest - ?dryRun=All 요청 여부 (admission까지 실행하고 저장하지 않음)
+func isDryRunRequest(r *http.Request) bool {
+	for _, value := range r.URL.Query()["dryRun"] {
+		if value == "All" {
+			return true
+		}
+	}
+	return false
+}
+
+// clientIdentity - 인증 토큰 해시 또는 원격 주소로 클라이언트 식별
+func clientIdentity(r *http.Request) string {
+	if auth := r.Header.Get("Authorization"); auth != "" {
+		hash := sha256.Sum256([]byte(auth))
+		return "token:" + hex.EncodeToString(hash[:8])
+	}
+	host, _, err := net.SplitHostPort(r.RemoteAddr)
+	if err != nil {
+		host = r.RemoteAddr
+	}
+	return "addr:" + host
+}
@@ -0,0 +1,49 @@
+package main
+
+import (
+	"net/http"
+	"net/http/httptest"
+	"testing"
+
+	"github.com/sirupsen/logrus"
+)
+
+func TestChurnThrottleIdentity(t *testing.T) {
+	churn := &ChurnThrottle{
+		logger:       logrus.New(),
+		maxWrites:    2,
+		allowedUsers: map[string]bool{"system:operator": true},
+		counters:     make(map[string]*churnCounter),
+	}
+	handler := churn.Wrap(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {}))
+
+	send := func(user, userAgent string) int {
+		r := httptest.NewRequest("PUT", "/api/v1/namespaces/default/configmaps/cm", nil)
+		r.RemoteAddr = "10.0.0.1:40000" // 모든 사용자가 같은 인증 프록시를 통해 접속
+		r.Header.Set("User-Agent", userAgent)
+		if user != "" {
+			r.Header.Set(remoteUserHeader, user)
+		}
+		w := httptest.NewRecorder()
+		handler.ServeHTTP(w, r)
+		return w.Code
+	}
+
+	for i := 0; i < 3; i++ {
+		send("alice", "kubectl")
+	}
+	if code := send("alice", "kubectl"); code != http.StatusTooManyRequests {
+		t.Errorf("alice over the limit: status = %d, want %d", code, http.StatusTooManyRequests)
+	}
+	if code := send("bob", "kubectl"); code != http.StatusOK {
+		t.Errorf("bob behind the same proxy: status = %d, want %d", code, http.StatusOK)
+	}
+	if code := send("alice", "kube-controller-manager/v1.28"); code != http.StatusTooManyRequests {
+		t.Errorf("alice with a system User-Agent: status = %d, want %d", code, http.StatusTooManyRequests)
+	}
+	for i := 0; i < 5; i++ {
+		if code := send("system:operator", "kubectl"); code != http.StatusOK {
+			t.Fatalf("allowlisted user: status = %d, want %d", code, http.StatusOK)
+		}
+	}
+}
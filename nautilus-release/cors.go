@@ -0,0 +1,86 @@
+// CORS Handler - 브라우저 대시보드용 CORS 및 WebSocket Origin 검증
+package main
+
+import (
+	"net/http"
+	"strings"
+
+	"github.com/sirupsen/logrus"
+)
+
+// CORSHandler - Origin 허용 목록 기반 CORS 처리
+type CORSHandler struct {
+	logger         *logrus.Logger
+	allowedOrigins map[string]bool
+}
+
+// NewCORSHandler - 새 CORS 핸들러 생성 (허용 목록이 비어 있으면 비활성화)
+func NewCORSHandler(logger *logrus.Logger) *CORSHandler {
+	allowed := make(map[string]bool)
+	for _, origin := range strings.Split(getEnvOrDefault("NAUTILUS_CORS_ALLOWED_ORIGINS", ""), ",") {
+		if origin = strings.TrimSpace(origin); origin != "" {
+			allowed[origin] = true
+		}
+	}
+
+	return &CORSHandler{
+		logger:         logger,
+		allowedOrigins: allowed,
+	}
+}
+
+// Wrap - CORS 헤더 추가, preflight 응답 및 WebSocket 업그레이드 Origin 검증
+func (c *CORSHandler) Wrap(next http.Handler) http.Handler {
+	return http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		origin := r.Header.Get("Origin")
+		if origin == "" {
+			next.ServeHTTP(w, r)
+			return
+		}
+
+		allowed := c.allowedOrigins[origin]
+
+		// 브라우저는 WebSocket에 CORS를 적용하지 않으므로 직접 Origin 검증
+		if isWebSocketUpgrade(r) {
+			if !allowed {
+				c.logger.Warnf("🚫 WebSocket upgrade from disallowed origin %s", origin)
+				http.Error(w, "Origin not allowed", http.StatusForbidden)
+				return
+			}
+			next.ServeHTTP(w, r)
+			return
+		}
+
+		if !allowed {
+			if r.Method == http.MethodOptions && r.Header.Get("Access-Control-Request-Method") != "" {
+				http.Error(w, "Origin not allowed", http.StatusForbidden)
+				return
+			}
+			next.ServeHTTP(w, r)
+			return
+		}
+
+		w.Header().Add("Vary", "Origin")
+		w.Header().Set("Access-Control-Allow-Origin", origin)
+		w.Header().Set("Access-Control-Allow-Credentials", "true")
+		w.Header().Set("Access-Control-Expose-Headers", "ETag, Content-Type, Retry-After, Warning")
+
+		// Preflight 요청
+		if r.Method == http.MethodOptions && r.Header.Get("Access-Control-Request-Method") != "" {
+			w.Header().Set("Access-Control-Allow-Methods", "GET, POST, PUT, PATCH, DELETE, OPTIONS")
+			if headers := r.Header.Get("Access-Control-Request-Headers"); headers != "" {
+				w.Header().Set("Access-Control-Allow-Headers", headers)
+			}
+			w.Header().Set("Access-Control-Max-Age", "600")
+			w.WriteHeader(http.StatusNoContent)
+			return
+		}
+
+		next.ServeHTTP(w, r)
+	})
+}
+
+// isWebSocketUpgrade - WebSocket 업그레이드 요청 여부
+func isWebSocketUpgrade(r *http.Request) bool {
+	return strings.EqualFold(r.Header.Get("Upgrade"), "websocket")
+}
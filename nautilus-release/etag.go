@@ -0,0 +1,138 @@
+// ETag Handler - resourceVersion 기반 ETag 및 If-None-Match 304 응답
+package main
+
+import (
+	"bytes"
+	"context"
+	"encoding/json"
+	"fmt"
+	"io"
+	"mime"
+	"net/http"
+	"strings"
+
+	"github.com/sirupsen/logrus"
+)
+
+// etagRequestKey - ETag 처리 대상 요청의 If-None-Match 값 저장용 context 키
+type etagRequestKey struct{}
+
+// ETagHandler - 단일 객체 GET 응답에 ETag 부여
+type ETagHandler struct {
+	logger *logrus.Logger
+}
+
+// NewETagHandler - 새 ETag 핸들러 생성
+func NewETagHandler(logger *logrus.Logger) *ETagHandler {
+	return &ETagHandler{
+		logger: logger,
+	}
+}
+
+// Wrap - 단일 객체 GET 요청을 ETag 처리 대상으로 표시
+func (h *ETagHandler) Wrap(next http.Handler) http.Handler {
+	return http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		if !isETagRequest(r) {
+			next.ServeHTTP(w, r)
+			return
+		}
+
+		ifNoneMatch := r.Header.Get("If-None-Match")
+		r.Header.Del("If-None-Match")
+		// resourceVersion을 읽을 수 있도록 비압축 응답 요청
+		r.Header.Del("Accept-Encoding")
+
+		ctx := context.WithValue(r.Context(), etagRequestKey{}, ifNoneMatch)
+		next.ServeHTTP(w, r.WithContext(ctx))
+	})
+}
+
+// ModifyResponse - ETag 헤더 설정 및 일치 시 304로 변환
+func (h *ETagHandler) ModifyResponse(resp *http.Response) error {
+	ifNoneMatch, ok := resp.Request.Context().Value(etagRequestKey{}).(string)
+	if !ok || resp.StatusCode != http.StatusOK ||
+		!strings.HasPrefix(resp.Header.Get("Content-Type"), "application/json") {
+		return nil
+	}
+
+	body, err := io.ReadAll(resp.Body)
+	resp.Body.Close()
+	if err != nil {
+		return fmt.Errorf("failed to read response for etag: %v", err)
+	}
+	resp.Body = io.NopCloser(bytes.NewReader(body))
+
+	var obj struct {
+		Metadata struct {
+			ResourceVersion string `json:"resourceVersion"`
+		} `json:"metadata"`
+	}
+	if err := json.Unmarshal(body, &obj); err != nil || obj.Metadata.ResourceVersion == "" {
+		return nil
+	}
+
+	redact, _ := resp.Request.Context().Value(secretRedactKey{}).(bool)
+	etag := resourceETag(obj.Metadata.ResourceVersion, resp.Header.Get("Content-Type"), redact)
+	resp.Header.Set("ETag", etag)
+	// 같은 URL이라도 Accept(as=Table 등)에 따라 표현이 다르므로 캐시가 구분하도록 표시
+	resp.Header.Add("Vary", "Accept")
+
+	if !etagMatches(ifNoneMatch, etag) {
+		return nil
+	}
+
+	h.logger.Debugf("♻️ Not modified: %s (%s)", resp.Request.URL.Path, etag)
+	resp.StatusCode = http.StatusNotModified
+	resp.Status = "304 Not Modified"
+	resp.Body = http.NoBody
+	resp.ContentLength = 0
+	resp.Header.Del("Content-Length")
+	resp.Header.Del("Content-Type")
+	return nil
+}
+
+// etagSkippedSubresources - 스트리밍/업스트림 전달 서브리소스 (본문을 버퍼링하지 않음)
+var etagSkippedSubresources = map[string]bool{
+	"proxy":       true,
+	"log":         true,
+	"exec":        true,
+	"attach":      true,
+	"portforward": true,
+}
+
+// isETagRequest - 이름이 지정된 리소스 GET 요청인지 확인
+// (LIST/watch, 디스커버리, /openapi, 서비스/노드 프록시와 로그는 스트리밍 유지 및 압축 보존을 위해 제외)
+func isETagRequest(r *http.Request) bool {
+	if r.Method != http.MethodGet || isWatchRequest(r) {
+		return false
+	}
+	info, ok := parseK8sPath(r.URL.Path)
+	return ok && info.Name != "" && !etagSkippedSubresources[info.Subresource]
+}
+
+// resourceETag - resourceVersion과 응답 표현(as=Table 등 미디어 타입 파라미터, Secret 마스킹)을 포함한 ETag
+func resourceETag(resourceVersion, contentType string, redacted bool) string {
+	tag := resourceVersion
+	if _, params, err := mime.ParseMediaType(contentType); err == nil && params["as"] != "" {
+		tag += "-" + params["as"] + "." + params["v"] + "." + params["g"]
+	}
+	// 마스킹된 Secret 응답은 원본 응답과 다른 ETag 사용
+	if redacted {
+		tag += "-redacted"
+	}
+	return `"` + tag + `"`
+}
+
+// etagMatches - If-None-Match 목록에 현재 ETag가 포함되는지 확인 (RFC 7232 약한 비교: W/ 접두사 무시)
+func etagMatches(ifNoneMatch, etag string) bool {
+	if ifNoneMatch == "" {
+		return false
+	}
+	for _, candidate := range strings.Split(ifNoneMatch, ",") {
+		candidate = strings.TrimPrefix(strings.TrimSpace(candidate), "W/")
+		if candidate == "*" || candidate == etag {
+			return true
+		}
+	}
+	return false
+}
@@ -0,0 +1,82 @@
+package main
+
+import (
+	"net/http/httptest"
+	"testing"
+)
+
+func TestETagMatches(t *testing.T) {
+	tests := []struct {
+		ifNoneMatch string
+		etag        string
+		want        bool
+	}{
+		{"", `"42"`, false},
+		{`"42"`, `"42"`, true},
+		{`"41"`, `"42"`, false},
+		{`42`, `"42"`, false},
+		{`"41", "42"`, `"42"`, true},
+		{`"41","43"`, `"42"`, false},
+		{`*`, `"42"`, true},
+		{`W/"42"`, `"42"`, true},
+		{`"42"`, `"42-redacted"`, false},
+		{`"42-redacted"`, `"42-redacted"`, true},
+	}
+
+	for _, tt := range tests {
+		if got := etagMatches(tt.ifNoneMatch, tt.etag); got != tt.want {
+			t.Errorf("etagMatches(%q, %q) = %v, want %v", tt.ifNoneMatch, tt.etag, got, tt.want)
+		}
+	}
+}
+
+func TestIsETagRequest(t *testing.T) {
+	tests := []struct {
+		method string
+		target string
+		want   bool
+	}{
+		{"GET", "/api/v1/namespaces/default/pods/web", true},
+		{"GET", "/api/v1/namespaces/default", true},
+		{"GET", "/apis/apps/v1/namespaces/default/deployments/web/scale", true},
+		{"GET", "/api/v1/namespaces/default/pods", false},
+		{"GET", "/api/v1/namespaces/default/pods/web?watch=true", false},
+		{"GET", "/api/v1/namespaces/default/pods/web/log", false},
+		{"GET", "/api/v1/namespaces/default/services/web/proxy", false},
+		{"GET", "/api/v1/namespaces/default/services/web/proxy/metrics", false},
+		{"GET", "/api/v1/namespaces/default/pods/web/exec", false},
+		{"GET", "/api/v1/nodes/node-1/proxy/stats", false},
+		{"GET", "/openapi/v3/apis/apps/v1", false},
+		{"GET", "/apis", false},
+		{"GET", "/api/v1", false},
+		{"GET", "/version", false},
+		{"PUT", "/api/v1/namespaces/default/pods/web", false},
+	}
+
+	for _, tt := range tests {
+		r := httptest.NewRequest(tt.method, tt.target, nil)
+		if got := isETagRequest(r); got != tt.want {
+			t.Errorf("isETagRequest(%s %s) = %v, want %v", tt.method, tt.target, got, tt.want)
+		}
+	}
+}
+
+func TestResourceETag(t *testing.T) {
+	tests := []struct {
+		contentType string
+		redacted    bool
+		want        string
+	}{
+		{"application/json", false, `"42"`},
+		{"application/json; charset=utf-8", false, `"42"`},
+		{"application/json", true, `"42-redacted"`},
+		{"application/json;as=Table;v=v1;g=meta.k8s.io", false, `"42-Table.v1.meta.k8s.io"`},
+		{"application/json;as=PartialObjectMetadata;v=v1;g=meta.k8s.io", true, `"42-PartialObjectMetadata.v1.meta.k8s.io-redacted"`},
+	}
+
+	for _, tt := range tests {
+		if got := resourceETag("42", tt.contentType, tt.redacted); got != tt.want {
+			t.Errorf("resourceETag(%q, %v) = %s, want %s", tt.contentType, tt.redacted, got, tt.want)
+		}
+	}
+}
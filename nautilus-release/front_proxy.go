@@ -0,0 +1,145 @@
+// Front Proxy Auth - 인증 프록시(X-Remote-User/Group) 헤더 신뢰 검증
+package main
+
+import (
+	"crypto/tls"
+	"crypto/x509"
+	"fmt"
+	"net/http"
+	"os"
+	"path/filepath"
+	"sort"
+	"strings"
+
+	"github.com/sirupsen/logrus"
+)
+
+// 인증 프록시가 설정하는 사용자 정보 헤더
+const (
+	remoteUserHeader        = "X-Remote-User"
+	remoteGroupHeader       = "X-Remote-Group"
+	remoteExtraHeaderPrefix = "X-Remote-Extra-"
+)
+
+// FrontProxyAuth - mTLS로 검증된 인증 프록시의 사용자 헤더만 K3s로 전달
+type FrontProxyAuth struct {
+	logger       *logrus.Logger
+	caFile       string
+	allowedNames map[string]bool
+	dataDir      string
+}
+
+// NewFrontProxyAuth - 새 인증 프록시 검증기 생성
+func NewFrontProxyAuth(logger *logrus.Logger, dataDir string) *FrontProxyAuth {
+	allowed := make(map[string]bool)
+	for _, name := range strings.Split(getEnvOrDefault("NAUTILUS_FRONT_PROXY_ALLOWED_NAMES", ""), ",") {
+		if name = strings.TrimSpace(name); name != "" {
+			allowed[name] = true
+		}
+	}
+
+	return &FrontProxyAuth{
+		logger:       logger,
+		caFile:       getEnvOrDefault("NAUTILUS_FRONT_PROXY_CA_FILE", ""),
+		allowedNames: allowed,
+		dataDir:      dataDir,
+	}
+}
+
+// Enabled - 인증 프록시 CA가 설정되었는지 여부
+func (f *FrontProxyAuth) Enabled() bool {
+	return f.caFile != ""
+}
+
+// ConfigureServerTLS - 인증 프록시 클라이언트 인증서를 검증하도록 서버 TLS 설정
+func (f *FrontProxyAuth) ConfigureServerTLS(config *tls.Config) error {
+	if !f.Enabled() {
+		return nil
+	}
+
+	caPEM, err := os.ReadFile(f.caFile)
+	if err != nil {
+		return fmt.Errorf("failed to read front proxy CA: %v", err)
+	}
+	pool := x509.NewCertPool()
+	if !pool.AppendCertsFromPEM(caPEM) {
+		return fmt.Errorf("no certificates found in front proxy CA %s", f.caFile)
+	}
+
+	// 일반 클라이언트는 인증서 없이도 접속 가능, 제시된 인증서는 반드시 검증
+	config.ClientCAs = pool
+	config.ClientAuth = tls.VerifyClientCertIfGiven
+	return nil
+}
+
+// ConfigureUpstreamTLS - K3s에 request-header 클라이언트 인증서 제시
+func (f *FrontProxyAuth) ConfigureUpstreamTLS(config *tls.Config) {
+	if !f.Enabled() {
+		return
+	}
+
+	// K3s가 시작된 뒤 생성되므로 요청 시점에 로드
+	config.GetClientCertificate = func(*tls.CertificateRequestInfo) (*tls.Certificate, error) {
+		tlsDir := filepath.Join(f.dataDir, "server", "tls")
+		cert, err := tls.LoadX509KeyPair(
+			filepath.Join(tlsDir, "client-auth-proxy.crt"),
+			filepath.Join(tlsDir, "client-auth-proxy.key"))
+		if err != nil {
+			f.logger.Warnf("⚠️ Failed to load K3s auth proxy client certificate: %v", err)
+			return &tls.Certificate{}, nil
+		}
+		return &cert, nil
+	}
+}
+
+// Wrap - 검증된 인증 프록시가 아니면 사용자 정보 헤더 제거
+func (f *FrontProxyAuth) Wrap(next http.Handler) http.Handler {
+	return http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		if f.isTrustedProxy(r) {
+			next.ServeHTTP(w, r)
+			return
+		}
+
+		stripped := false
+		for name := range r.Header {
+			canonical := http.CanonicalHeaderKey(name)
+			if canonical == remoteUserHeader || canonical == remoteGroupHeader ||
+				strings.HasPrefix(canonical, remoteExtraHeaderPrefix) {
+				r.Header.Del(name)
+				stripped = true
+			}
+		}
+		if stripped {
+			f.logger.Warnf("🚫 Stripped untrusted front-proxy headers from %s", r.RemoteAddr)
+		}
+
+		next.ServeHTTP(w, r)
+	})
+}
+
+// isTrustedProxy - mTLS로 검증된 허용 인증 프록시 요청인지 확인
+func (f *FrontProxyAuth) isTrustedProxy(r *http.Request) bool {
+	if !f.Enabled() || r.TLS == nil || len(r.TLS.VerifiedChains) == 0 {
+		return false
+	}
+	if len(f.allowedNames) == 0 {
+		return true
+	}
+	return f.allowedNames[r.TLS.VerifiedChains[0][0].Subject.CommonName]
+}
+
+// authenticatedUser - 검증된 인증 프록시 사용자명, 없으면 clientIdentity
+// (X-Remote-User는 FrontProxyAuth.Wrap을 통과한 뒤에는 신뢰된 프록시 요청에만 남아 있음)
+func authenticatedUser(r *http.Request) string {
+	if user := r.Header.Get(remoteUserHeader); user != "" {
+		return user
+	}
+	return clientIdentity(r)
+}
+
+// authenticatedPrincipal - 사용자와 그룹을 합친 요청 주체 (같은 사용자명이라도 그룹이 다르면 권한이 다르므로 구분)
+func authenticatedPrincipal(r *http.Request) string {
+	groups := append([]string(nil), r.Header.Values(remoteGroupHeader)...)
+	sort.Strings(groups)
+	return authenticatedUser(r) + "|" + strings.Join(groups, ",")
+}
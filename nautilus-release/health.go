@@ -0,0 +1,106 @@
+// Health Checks - /livez, /readyz, /healthz 개별 체크 결과 (?verbose, ?exclude 지원)
+package main
+
+import (
+	"bytes"
+	"fmt"
+	"net/http"
+)
+
+// healthCheck - 이름 있는 개별 상태 체크 (nil이면 통과)
+type healthCheck struct {
+	name  string
+	check func() error
+}
+
+// livenessChecks - 프로세스 재시작이 필요한 상태인지 확인 (k3s supervisor가 재시작을 포기한 경우만 실패)
+func (a *APIServer) livenessChecks() []healthCheck {
+	return []healthCheck{
+		{"ping", func() error { return nil }},
+		{"k3s-supervisor", func() error {
+			if a.k3sMgr.StartupPhase() == phaseGaveUp {
+				return fmt.Errorf("k3s supervisor gave up: %s", a.k3sMgr.StartupDetail())
+			}
+			return nil
+		}},
+	}
+}
+
+// readinessChecks - 트래픽을 받을 수 있는 상태인지 확인
+func (a *APIServer) readinessChecks() []healthCheck {
+	return append(a.livenessChecks(),
+		healthCheck{"shutdown", func() error {
+			if a.shuttingDown.Load() {
+				return fmt.Errorf("server is shutting down")
+			}
+			return nil
+		}},
+		healthCheck{"k3s", func() error {
+			if !a.k3sMgr.IsRunning() {
+				if detail := a.k3sMgr.StartupDetail(); detail != "" {
+					return fmt.Errorf("k3s is not ready (%s: %s)", a.k3sMgr.StartupPhase(), detail)
+				}
+				return fmt.Errorf("k3s is not ready (%s)", a.k3sMgr.StartupPhase())
+			}
+			return nil
+		}},
+		healthCheck{"memory-pressure", func() error {
+			if level := a.memMonitor.Level(); level == MemoryPressureCritical {
+				return fmt.Errorf("memory pressure is %s", level)
+			}
+			return nil
+		}},
+	)
+}
+
+// handleLive - 활성 상태 확인
+func (a *APIServer) handleLive(w http.ResponseWriter, r *http.Request) {
+	serveHealthChecks(w, r, "livez", "ok", a.livenessChecks())
+}
+
+// handleHealth - 헬스체크 (/livez와 동일한 체크, 기존 프로브 호환을 위해 성공 시 "OK")
+func (a *APIServer) handleHealth(w http.ResponseWriter, r *http.Request) {
+	serveHealthChecks(w, r, "healthz", "OK", a.livenessChecks())
+}
+
+// handleReady - 준비 상태 확인
+func (a *APIServer) handleReady(w http.ResponseWriter, r *http.Request) {
+	serveHealthChecks(w, r, "readyz", "ok", a.readinessChecks())
+}
+
+// serveHealthChecks - kube-apiserver와 같은 형식으로 체크 결과 출력 (모두 통과하고 verbose가 아니면 okBody)
+func serveHealthChecks(w http.ResponseWriter, r *http.Request, endpoint, okBody string, checks []healthCheck) {
+	excluded := make(map[string]bool)
+	for _, name := range r.URL.Query()["exclude"] {
+		excluded[name] = true
+	}
+	_, verbose := r.URL.Query()["verbose"]
+
+	var output bytes.Buffer
+	failed := false
+	for _, c := range checks {
+		if excluded[c.name] {
+			fmt.Fprintf(&output, "[+]%s excluded: ok\n", c.name)
+			continue
+		}
+		if err := c.check(); err != nil {
+			fmt.Fprintf(&output, "[-]%s failed: %v\n", c.name, err)
+			failed = true
+		} else {
+			fmt.Fprintf(&output, "[+]%s ok\n", c.name)
+		}
+	}
+
+	w.Header().Set("Content-Type", "text/plain; charset=utf-8")
+	w.Header().Set("X-Content-Type-Options", "nosniff")
+	if failed {
+		w.WriteHeader(http.StatusServiceUnavailable)
+		fmt.Fprintf(w, "%s%s check failed\n", output.String(), endpoint)
+		return
+	}
+	if verbose {
+		fmt.Fprintf(w, "%s%s check passed\n", output.String(), endpoint)
+		return
+	}
+	fmt.Fprint(w, okBody)
+}
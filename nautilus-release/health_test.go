@@ -0,0 +1,34 @@
+package main
+
+import (
+	"errors"
+	"net/http"
+	"net/http/httptest"
+	"testing"
+)
+
+func TestServeHealthChecks(t *testing.T) {
+	pass := healthCheck{"ping", func() error { return nil }}
+	fail := healthCheck{"k3s", func() error { return errors.New("k3s is not ready (failed: download error)") }}
+
+	tests := []struct {
+		target   string
+		checks   []healthCheck
+		wantCode int
+		wantBody string
+	}{
+		{"/healthz", []healthCheck{pass}, http.StatusOK, "OK"},
+		{"/healthz?verbose", []healthCheck{pass}, http.StatusOK, "[+]ping ok\nhealthz check passed\n"},
+		{"/healthz", []healthCheck{pass, fail}, http.StatusServiceUnavailable,
+			"[+]ping ok\n[-]k3s failed: k3s is not ready (failed: download error)\nhealthz check failed\n"},
+		{"/healthz?exclude=k3s", []healthCheck{pass, fail}, http.StatusOK, "OK"},
+	}
+
+	for _, tt := range tests {
+		w := httptest.NewRecorder()
+		serveHealthChecks(w, httptest.NewRequest("GET", tt.target, nil), "healthz", "OK", tt.checks)
+		if w.Code != tt.wantCode || w.Body.String() != tt.wantBody {
+			t.Errorf("%s = %d %q, want %d %q", tt.target, w.Code, w.Body.String(), tt.wantCode, tt.wantBody)
+		}
+	}
+}
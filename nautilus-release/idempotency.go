@@ -0,0 +1,228 @@
+// Idempotency Keys - 네트워크 타임아웃 후 재시도된 생성 요청의 원래 응답 재전송
+package main
+
+import (
+	"bytes"
+	"context"
+	"crypto/sha256"
+	"encoding/hex"
+	"errors"
+	"fmt"
+	"io"
+	"net/http"
+	"sync"
+	"time"
+
+	"github.com/sirupsen/logrus"
+)
+
+const (
+	// idempotencyKeyHeader - 클라이언트가 POST 요청에 붙이는 멱등성 키
+	idempotencyKeyHeader = "Idempotency-Key"
+	// idempotencyReplayedHeader - 저장된 응답을 재전송했음을 표시
+	idempotencyReplayedHeader = "Idempotent-Replayed"
+	// maxIdempotentResponseBytes - 저장할 응답 본문 최대 크기 (초과 시 저장하지 않음)
+	maxIdempotentResponseBytes = 1 << 20
+)
+
+// idempotentEntry - 멱등성 키별 요청 상태 및 응답
+type idempotentEntry struct {
+	bodyHash    string
+	done        bool
+	status      int
+	contentType string
+	body        []byte
+	expiresAt   time.Time
+}
+
+// IdempotencyCache - 주체별 최근 멱등성 키와 생성 응답 보관
+type IdempotencyCache struct {
+	logger  *logrus.Logger
+	ttl     time.Duration
+	entries map[string]*idempotentEntry
+	mutex   sync.Mutex
+}
+
+// NewIdempotencyCache - 새 멱등성 캐시 생성
+func NewIdempotencyCache(logger *logrus.Logger) *IdempotencyCache {
+	ttl, err := time.ParseDuration(getEnvOrDefault("NAUTILUS_IDEMPOTENCY_TTL", "10m"))
+	if err != nil || ttl <= 0 {
+		ttl = 10 * time.Minute
+	}
+
+	return &IdempotencyCache{
+		logger:  logger,
+		ttl:     ttl,
+		entries: make(map[string]*idempotentEntry),
+	}
+}
+
+// Start - 만료된 항목 주기적 정리
+func (c *IdempotencyCache) Start(ctx context.Context) {
+	ticker := time.NewTicker(time.Minute)
+	defer ticker.Stop()
+
+	for {
+		select {
+		case <-ctx.Done():
+			return
+		case <-ticker.C:
+			c.cleanup()
+		}
+	}
+}
+
+// Wrap - 같은 주체/경로/키로 재시도된 POST에 원래 응답 반환
+func (c *IdempotencyCache) Wrap(next http.Handler) http.Handler {
+	return http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		idempotencyKey := r.Header.Get(idempotencyKeyHeader)
+		if r.Method != http.MethodPost || idempotencyKey == "" || isDryRunRequest(r) {
+			next.ServeHTTP(w, r)
+			return
+		}
+		r.Header.Del(idempotencyKeyHeader)
+
+		// 같은 키로 다른 본문을 보내면 거절하기 위해 본문 해시 비교
+		body, err := io.ReadAll(r.Body)
+		if err != nil {
+			var tooLarge *http.MaxBytesError
+			if errors.As(err, &tooLarge) {
+				http.Error(w, fmt.Sprintf("Request entity too large: limit is %d bytes", tooLarge.Limit),
+					http.StatusRequestEntityTooLarge)
+				return
+			}
+			http.Error(w, "Failed to read request body", http.StatusBadRequest)
+			return
+		}
+		r.Body = io.NopCloser(bytes.NewReader(body))
+		bodySum := sha256.Sum256(body)
+		bodyHash := hex.EncodeToString(bodySum[:])
+
+		// 인증 프록시 뒤의 사용자들이 프록시 주소/인증서를 공유하므로 검증된 사용자와 그룹으로 구분
+		key := authenticatedPrincipal(r) + "|" + r.URL.Path + "|" + idempotencyKey
+
+		c.mutex.Lock()
+		entry, exists := c.entries[key]
+		if exists && time.Now().After(entry.expiresAt) {
+			exists = false
+		}
+		if !exists {
+			entry = &idempotentEntry{bodyHash: bodyHash, expiresAt: time.Now().Add(c.ttl)}
+			c.entries[key] = entry
+		}
+		c.mutex.Unlock()
+
+		if exists {
+			c.replay(w, r, entry, bodyHash)
+			return
+		}
+
+		recorder := &idempotencyRecorder{ResponseWriter: w, status: http.StatusOK}
+		defer func() {
+			// 패닉 시에도 항목을 정리하여 재시도가 "처리 중"에 묶이지 않도록 함
+			panicked := recover()
+			c.finish(key, entry, recorder, panicked != nil)
+			if panicked != nil {
+				panic(panicked)
+			}
+		}()
+
+		// 클라이언트가 타임아웃으로 끊어도 이미 전송된 생성 요청은 끝까지 처리하여 응답 저장
+		next.ServeHTTP(recorder, r.WithContext(context.WithoutCancel(r.Context())))
+	})
+}
+
+// finish - 완료된 응답 저장 (서버 오류, 너무 큰 응답, 패닉이면 삭제하여 재시도가 다시 실행되도록 함)
+func (c *IdempotencyCache) finish(key string, entry *idempotentEntry, recorder *idempotencyRecorder, panicked bool) {
+	c.mutex.Lock()
+	defer c.mutex.Unlock()
+
+	if panicked || recorder.status >= http.StatusInternalServerError || recorder.overflow {
+		if c.entries[key] == entry {
+			delete(c.entries, key)
+		}
+		return
+	}
+	entry.done = true
+	entry.status = recorder.status
+	entry.contentType = recorder.Header().Get("Content-Type")
+	entry.body = recorder.body.Bytes()
+}
+
+// replay - 저장된 응답 재전송 (처리 중이거나 본문이 다르면 409)
+func (c *IdempotencyCache) replay(w http.ResponseWriter, r *http.Request, entry *idempotentEntry, bodyHash string) {
+	c.mutex.Lock()
+	done, sameBody := entry.done, entry.bodyHash == bodyHash
+	status, contentType, body := entry.status, entry.contentType, entry.body
+	c.mutex.Unlock()
+
+	if !sameBody {
+		http.Error(w, "Idempotency-Key was already used with a different request body", http.StatusConflict)
+		return
+	}
+	if !done {
+		w.Header().Set("Retry-After", "1")
+		http.Error(w, "A request with this Idempotency-Key is still in progress", http.StatusConflict)
+		return
+	}
+
+	c.logger.Debugf("🔁 Replaying response for idempotent %s %s", r.Method, r.URL.Path)
+	if contentType != "" {
+		w.Header().Set("Content-Type", contentType)
+	}
+	w.Header().Set(idempotencyReplayedHeader, "true")
+	w.WriteHeader(status)
+	w.Write(body)
+}
+
+// cleanup - 만료된 항목 제거 (완료되지 않은 항목 포함)
+func (c *IdempotencyCache) cleanup() {
+	c.mutex.Lock()
+	defer c.mutex.Unlock()
+
+	now := time.Now()
+	for key, entry := range c.entries {
+		if now.After(entry.expiresAt) {
+			delete(c.entries, key)
+		}
+	}
+}
+
+// idempotencyRecorder - 응답을 클라이언트에 전달하면서 상태 코드와 본문 복사
+// (클라이언트 연결이 끊겨도 업스트림 응답을 끝까지 읽어 저장)
+type idempotencyRecorder struct {
+	http.ResponseWriter
+	status     int
+	body       bytes.Buffer
+	overflow   bool
+	clientGone bool
+}
+
+// WriteHeader - 응답 코드 기록
+func (rec *idempotencyRecorder) WriteHeader(status int) {
+	rec.status = status
+	rec.ResponseWriter.WriteHeader(status)
+}
+
+// Write - 본문 복사 (한도 초과 시 복사 중단, 클라이언트 쓰기 실패는 무시)
+func (rec *idempotencyRecorder) Write(data []byte) (int, error) {
+	if !rec.overflow {
+		if rec.body.Len()+len(data) > maxIdempotentResponseBytes {
+			rec.overflow = true
+			rec.body.Reset()
+		} else {
+			rec.body.Write(data)
+		}
+	}
+	if !rec.clientGone {
+		if _, err := rec.ResponseWriter.Write(data); err != nil {
+			rec.clientGone = true
+		}
+	}
+	return len(data), nil
+}
+
+// Unwrap - http.ResponseController가 원래 ResponseWriter에 접근하도록 허용
+func (rec *idempotencyRecorder) Unwrap() http.ResponseWriter {
+	return rec.ResponseWriter
+}
@@ -0,0 +1,54 @@
+package main
+
+import (
+	"net/http"
+	"net/http/httptest"
+	"strings"
+	"testing"
+	"time"
+
+	"github.com/sirupsen/logrus"
+)
+
+func TestIdempotencyKeyIsPerUser(t *testing.T) {
+	cache := &IdempotencyCache{
+		logger:  logrus.New(),
+		ttl:     time.Minute,
+		entries: make(map[string]*idempotentEntry),
+	}
+	upstreamCalls := 0
+	handler := cache.Wrap(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		upstreamCalls++
+		w.WriteHeader(http.StatusCreated)
+		w.Write([]byte(`{"kind":"ConfigMap","metadata":{"name":"cm"}}`))
+	}))
+
+	send := func(user string, groups ...string) *httptest.ResponseRecorder {
+		r := httptest.NewRequest("POST", "/api/v1/namespaces/default/configmaps", strings.NewReader(`{"metadata":{"name":"cm"}}`))
+		r.RemoteAddr = "10.0.0.1:40000" // 모든 사용자가 같은 인증 프록시를 통해 접속
+		r.Header.Set(idempotencyKeyHeader, "retry-1")
+		r.Header.Set(remoteUserHeader, user)
+		for _, group := range groups {
+			r.Header.Add(remoteGroupHeader, group)
+		}
+		w := httptest.NewRecorder()
+		handler.ServeHTTP(w, r)
+		return w
+	}
+
+	send("alice", "dev")
+	if w := send("alice", "dev"); w.Header().Get(idempotencyReplayedHeader) != "true" || upstreamCalls != 1 {
+		t.Errorf("retry by the same user: replayed=%q upstream calls=%d, want replay and 1 call",
+			w.Header().Get(idempotencyReplayedHeader), upstreamCalls)
+	}
+
+	if w := send("bob", "dev"); w.Header().Get(idempotencyReplayedHeader) != "" || upstreamCalls != 2 {
+		t.Errorf("same key from another user: replayed=%q upstream calls=%d, want upstream call",
+			w.Header().Get(idempotencyReplayedHeader), upstreamCalls)
+	}
+
+	if w := send("alice", "admins"); w.Header().Get(idempotencyReplayedHeader) != "" || upstreamCalls != 3 {
+		t.Errorf("same user with other groups: replayed=%q upstream calls=%d, want upstream call",
+			w.Header().Get(idempotencyReplayedHeader), upstreamCalls)
+	}
+}
@@ -7,11 +7,18 @@ import (
 	"os"
 	"os/exec"
 	"path/filepath"
+	"sync"
 	"time"
 
 	"github.com/sirupsen/logrus"
 )
 
+// k3sBinaryPath - K3s 서버 바이너리 경로
+const k3sBinaryPath = "/usr/local/bin/k3s"
+
+// inPlaceResizeFeatureGate - 파드 재시작 없는 CPU/메모리 변경 (워커 kubelet에도 같은 게이트 필요)
+const inPlaceResizeFeatureGate = "InPlacePodVerticalScaling=true"
+
 // K3sManager - K3s 마스터 노드 관리
 type K3sManager struct {
 	logger           *logrus.Logger
@@ -21,17 +28,51 @@ type K3sManager struct {
 	running          bool
 	workerPool       *WorkerPool
 	sealTokenManager *SealTokenManager
+	trustedClock     *TrustedClock
+	maintenance      *MaintenanceMode
+	startupPhase     string
+	startupDetail    string // 현재 단계의 상세 사유 (실패 원인, 준비 지연 등)
+	phaseMutex       sync.RWMutex
+	stopWaiting      context.CancelFunc // 현재 프로세스의 준비 대기 중단
+	ready            chan struct{}
+	readyOnce        sync.Once
+	snapshotMutex    sync.Mutex
 }
 
+// K3s 시작 단계 (/readyz 진행 상황 표시용)
+const (
+	phasePending     = "pending"
+	phaseDirectories = "setting-up-directories"
+	phaseBinary      = "ensuring-binary"
+	phaseStarting    = "starting-server"
+	phaseWaiting     = "waiting-for-apiserver"
+	phaseReady       = "ready"
+	phaseFailed      = "failed"
+	phaseGaveUp      = "restart-limit-exceeded" // supervisor가 재시작을 포기함 (컨테이너 재시작 필요)
+)
+
+// K3s API 준비 대기 간격 (2s부터 두 배씩, 최대 30s) 및 지연 보고 기준
+const (
+	readyPollInterval    = 2 * time.Second
+	readyPollMaxInterval = 30 * time.Second
+	readyWarnAfter       = 60 * time.Second
+)
+
 // NewK3sManager - 새 K3s Manager 생성
 func NewK3sManager(logger *logrus.Logger) *K3sManager {
+	trustedClock := NewTrustedClock(logger)
+
 	return &K3sManager{
 		logger:           logger,
 		dataDir:          "/var/lib/rancher/k3s",
 		configFile:       "/etc/rancher/k3s/k3s.yaml",
 		running:          false,
 		workerPool:       NewWorkerPool(logger),
-		sealTokenManager: NewSealTokenManager(logger),
+		sealTokenManager: NewSealTokenManager(logger, trustedClock),
+		trustedClock:     trustedClock,
+		maintenance:      NewMaintenanceMode(logger),
+		startupPhase:     phasePending,
+		ready:            make(chan struct{}),
 	}
 }
 
@@ -39,24 +80,36 @@ func NewK3sManager(logger *logrus.Logger) *K3sManager {
 func (k *K3sManager) Start(ctx context.Context) {
 	k.logger.Info("🔧 Starting K3s Manager...")
 
+	// 토큰/인증서 만료 검사에 사용할 신뢰 시간원 동기화
+	go k.trustedClock.Start(ctx)
+
 	// 데이터 디렉토리 생성
+	k.setStartupPhase(phaseDirectories)
 	if err := k.setupDirectories(); err != nil {
 		k.logger.Errorf("❌ Failed to setup directories: %v", err)
+		k.setStartupStatus(phaseFailed, err.Error())
 		return
 	}
 
 	// K3s 바이너리 확인 및 다운로드
+	k.setStartupPhase(phaseBinary)
 	if err := k.ensureK3sBinary(); err != nil {
 		k.logger.Errorf("❌ Failed to ensure K3s binary: %v", err)
+		k.setStartupStatus(phaseFailed, err.Error())
 		return
 	}
 
 	// K3s 서버 시작
+	k.setStartupPhase(phaseStarting)
 	if err := k.startK3sServer(ctx); err != nil {
 		k.logger.Errorf("❌ Failed to start K3s server: %v", err)
+		k.setStartupStatus(phaseFailed, err.Error())
 		return
 	}
 
+	// 프로세스 종료 감시 및 자동 재시작
+	go k.superviseProcess(ctx)
+
 	k.logger.Info("✅ K3s Manager started successfully")
 }
 
@@ -81,10 +134,8 @@ func (k *K3sManager) setupDirectories() error {
 
 // ensureK3sBinary - K3s 바이너리 확인 및 다운로드
 func (k *K3sManager) ensureK3sBinary() error {
-	k3sPath := "/usr/local/bin/k3s"
-
 	// 이미 존재하는지 확인
-	if _, err := os.Stat(k3sPath); err == nil {
+	if _, err := os.Stat(k3sBinaryPath); err == nil {
 		k.logger.Info("📦 K3s binary already exists")
 		return nil
 	}
@@ -115,6 +166,8 @@ func (k *K3sManager) startK3sServer(ctx context.Context) error {
 	args := []string{
 		"server",
 		"--data-dir", k.dataDir,
+		// Worker의 k3s agent가 join/kubelet 통신에 6443을 사용하므로 외부에 바인딩
+		// (이 포트로 직접 접속하면 8080 프록시의 보호 기능이 적용되지 않으므로 방화벽으로 Worker만 허용)
 		"--bind-address", "0.0.0.0",
 		"--https-listen-port", "6443",
 		"--disable", "traefik",    // Traefik 비활성화 (우리가 API Gateway 사용)
@@ -123,9 +176,34 @@ func (k *K3sManager) startK3sServer(ctx context.Context) error {
 		"--write-kubeconfig-mode", "644",
 		"--node-name", "nautilus-master",
 		"--cluster-init", // 단일 노드 클러스터로 시작
+		// 내장 admission 플러그인 체인 (NodeRestriction: kubelet은 자신의 Node/바인딩된 Pod만 수정 가능)
+		"--kube-apiserver-arg", "enable-admission-plugins=" + admissionPluginsArg(),
+		// WatchList: sendInitialEvents=true watch로 LIST 없이 초기 상태 수신 (streaming list)
+		// InPlacePodVerticalScaling: pods/resize로 재시작 없이 리소스 변경 (apiserver/scheduler/kubelet 모두 필요)
+		"--kube-apiserver-arg", "feature-gates=WatchList=true," + inPlaceResizeFeatureGate,
+		"--kube-scheduler-arg", "feature-gates=" + inPlaceResizeFeatureGate,
+		"--kubelet-arg", "feature-gates=" + inPlaceResizeFeatureGate,
+		// watch는 최대 NAUTILUS_WATCH_REAUTH_SECONDS 초 후 종료되어 재연결 시 RBAC 재인가 (권한 회수 유예 시간)
+		"--kube-apiserver-arg", "min-request-timeout=" + minRequestTimeoutArg(),
+	}
+
+	// kube-apiserver 기본 admission 플러그인 중 비활성화할 목록 (NodeRestriction은 제외)
+	if plugins := disableAdmissionPluginsArg(); plugins != "" {
+		args = append(args, "--kube-apiserver-arg", "disable-admission-plugins="+plugins)
 	}
 
-	k.process = exec.CommandContext(ctx, "/usr/local/bin/k3s", args...)
+	// Secret 저장 시 AES 암호화 (k3s가 암호화 키 생성 및 EncryptionConfiguration 관리)
+	if getEnvOrDefault("NAUTILUS_SECRETS_ENCRYPTION", "false") == "true" {
+		args = append(args, "--secrets-encryption")
+	}
+
+	// OIDC 인증 (NAUTILUS_OIDC_ISSUER_URL 등 설정 시)
+	args = append(args, envAPIServerArgs(oidcAPIServerArgs)...)
+
+	// TokenReview 웹훅 인증 (NAUTILUS_TOKEN_WEBHOOK_CONFIG_FILE 설정 시)
+	args = append(args, envAPIServerArgs(tokenWebhookAPIServerArgs)...)
+
+	k.process = exec.CommandContext(ctx, k3sBinaryPath, args...)
 	k.process.Stdout = os.Stdout
 	k.process.Stderr = os.Stderr
 
@@ -142,39 +220,140 @@ func (k *K3sManager) startK3sServer(ctx context.Context) error {
 	k.running = true
 	k.logger.Info("🎯 K3s server started on port 6443")
 
-	// K3s가 준비될 때까지 대기
-	go k.waitForReady()
+	// K3s가 준비될 때까지 대기 (프로세스가 종료되면 supervisor가 대기 중단)
+	k.setStartupPhase(phaseWaiting)
+	waitCtx, stopWaiting := context.WithCancel(ctx)
+	k.stopWaiting = stopWaiting
+	go k.waitForReady(waitCtx)
 
-	// 프로세스 종료 감시
-	go func() {
+	return nil
+}
+
+// superviseProcess - K3s 프로세스 종료 감시 및 지수 백오프 재시작
+func (k *K3sManager) superviseProcess(ctx context.Context) {
+	backoff := newRestartBackoff()
+	maxRestarts := maxRestartsFromEnv()
+	failures := 0
+
+	for {
+		startedAt := time.Now()
 		err := k.process.Wait()
 		k.running = false
+		k.stopWaiting()
 		if err != nil {
 			k.logger.Errorf("❌ K3s process exited with error: %v", err)
 		} else {
 			k.logger.Info("🛑 K3s process exited normally")
 		}
-	}()
 
-	return nil
+		// 정상 종료 요청이면 재시작하지 않음
+		if ctx.Err() != nil {
+			return
+		}
+
+		if time.Since(startedAt) >= stableRunDuration {
+			backoff.Reset()
+			failures = 0
+		}
+
+		// 재시작 성공 시까지 백오프하며 재시도
+		for {
+			failures++
+			if failures > maxRestarts {
+				k.logger.Errorf("🚨 K3s failed %d times in a row, giving up - manual intervention or failover required", failures)
+				k.setStartupStatus(phaseGaveUp, fmt.Sprintf("k3s failed %d times in a row", failures))
+				return
+			}
+
+			delay := backoff.Next()
+			k.logger.Warnf("🔁 Restarting K3s in %s (attempt %d/%d)", delay, failures, maxRestarts)
+			select {
+			case <-ctx.Done():
+				return
+			case <-time.After(delay):
+			}
+
+			k.setStartupPhase(phaseStarting)
+			if err := k.startK3sServer(ctx); err != nil {
+				k.logger.Errorf("❌ Failed to restart K3s server: %v", err)
+				continue
+			}
+			break
+		}
+	}
 }
 
-// waitForReady - K3s가 준비될 때까지 대기
-func (k *K3sManager) waitForReady() {
+// waitForReady - K3s가 준비될 때까지 백오프하며 대기 (제한 시간이 지나도 포기하지 않고 /readyz에 지연만 보고)
+func (k *K3sManager) waitForReady(ctx context.Context) {
 	k.logger.Info("⏳ Waiting for K3s to be ready...")
 
-	maxRetries := 30
-	for i := 0; i < maxRetries; i++ {
-		if k.checkK3sReady() {
-			k.logger.Info("✅ K3s is ready!")
-			return
+	onSlow := func() {
+		k.logger.Errorf("❌ K3s is not ready after %s, still waiting", readyWarnAfter)
+		k.setStartupStatus(phaseWaiting, fmt.Sprintf("not ready after %s", readyWarnAfter))
+	}
+	if !pollUntilReady(ctx, k.checkK3sReady, readyPollInterval, readyPollMaxInterval, readyWarnAfter, onSlow) {
+		return
+	}
+
+	k.logger.Info("✅ K3s is ready!")
+	k.setStartupPhase(phaseReady)
+	k.readyOnce.Do(func() { close(k.ready) })
+}
+
+// pollUntilReady - check가 성공할 때까지 간격을 두 배씩 늘리며 반복 (slowAfter 경과 시 onSlow 1회 호출)
+// ctx가 취소되면 false
+func pollUntilReady(ctx context.Context, check func() bool, interval, maxInterval, slowAfter time.Duration, onSlow func()) bool {
+	start := time.Now()
+	slow := false
+	for {
+		if check() {
+			return true
+		}
+		if !slow && time.Since(start) >= slowAfter {
+			slow = true
+			onSlow()
 		}
 
-		time.Sleep(2 * time.Second)
-		k.logger.Debugf("🔄 Checking K3s readiness... (%d/%d)", i+1, maxRetries)
+		select {
+		case <-ctx.Done():
+			return false
+		case <-time.After(interval):
+		}
+		interval = min(interval*2, maxInterval)
 	}
+}
+
+// Ready - K3s API 서버가 처음 준비되면 닫히는 채널
+func (k *K3sManager) Ready() <-chan struct{} {
+	return k.ready
+}
+
+// StartupPhase - 현재 시작 단계
+func (k *K3sManager) StartupPhase() string {
+	k.phaseMutex.RLock()
+	defer k.phaseMutex.RUnlock()
+	return k.startupPhase
+}
+
+// StartupDetail - 현재 단계의 상세 사유 (없으면 빈 문자열)
+func (k *K3sManager) StartupDetail() string {
+	k.phaseMutex.RLock()
+	defer k.phaseMutex.RUnlock()
+	return k.startupDetail
+}
+
+// setStartupPhase - 시작 단계 갱신 (이전 단계의 상세 사유는 삭제)
+func (k *K3sManager) setStartupPhase(phase string) {
+	k.setStartupStatus(phase, "")
+}
 
-	k.logger.Error("❌ K3s failed to become ready within timeout")
+// setStartupStatus - 시작 단계와 상세 사유 갱신
+func (k *K3sManager) setStartupStatus(phase, detail string) {
+	k.phaseMutex.Lock()
+	k.startupPhase = phase
+	k.startupDetail = detail
+	k.phaseMutex.Unlock()
+	k.logger.Debugf("🪜 K3s startup phase: %s %s", phase, detail)
 }
 
 // checkK3sReady - K3s 준비 상태 확인
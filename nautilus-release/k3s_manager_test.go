@@ -0,0 +1,43 @@
+package main
+
+import (
+	"context"
+	"testing"
+	"time"
+)
+
+func TestPollUntilReadyAfterDeadline(t *testing.T) {
+	start := time.Now()
+	slowAfter := 20 * time.Millisecond
+	check := func() bool { return time.Since(start) > 3*slowAfter }
+	slowCalls := 0
+
+	ready := pollUntilReady(context.Background(), check, time.Millisecond, 10*time.Millisecond, slowAfter,
+		func() { slowCalls++ })
+
+	if !ready {
+		t.Fatal("pollUntilReady gave up, want it to keep polling past the deadline")
+	}
+	if slowCalls != 1 {
+		t.Errorf("onSlow called %d times, want 1", slowCalls)
+	}
+}
+
+func TestPollUntilReadyImmediately(t *testing.T) {
+	slowCalls := 0
+	ready := pollUntilReady(context.Background(), func() bool { return true }, time.Millisecond, time.Millisecond, 0,
+		func() { slowCalls++ })
+
+	if !ready || slowCalls != 0 {
+		t.Errorf("pollUntilReady = %v with %d slow calls, want true with 0", ready, slowCalls)
+	}
+}
+
+func TestPollUntilReadyCanceled(t *testing.T) {
+	ctx, cancel := context.WithTimeout(context.Background(), 20*time.Millisecond)
+	defer cancel()
+
+	if pollUntilReady(ctx, func() bool { return false }, time.Millisecond, 5*time.Millisecond, time.Hour, func() {}) {
+		t.Error("pollUntilReady = true for a check that never succeeds")
+	}
+}
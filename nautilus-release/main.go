@@ -3,6 +3,7 @@ package main
 
 import (
 	"context"
+	"flag"
 	"os"
 	"os/signal"
 	"syscall"
@@ -11,10 +12,24 @@ import (
 )
 
 func main() {
+	dryRun := flag.Bool("dry-run", false, "validate configuration and exit without opening any ports")
+	flag.Parse()
+
 	// 로거 초기화
 	logger := logrus.New()
 	logger.SetLevel(logrus.DebugLevel)
 
+	// 설정 검증 - 오류가 있으면 컴포넌트 시작 전에 종료
+	report := validateConfig()
+	logConfigReport(logger, report)
+	if !report.Ready() {
+		os.Exit(1)
+	}
+	if *dryRun {
+		logger.Info("🧪 Dry run complete, exiting")
+		return
+	}
+
 	// Context 생성
 	ctx, cancel := context.WithCancel(context.Background())
 	defer cancel()
@@ -30,10 +45,18 @@ func main() {
 	// Sui Integration 초기화
 	suiIntegration := NewSuiIntegration(logger, k3sMgr)
 
-	// 컴포넌트 시작
+	// 컴포넌트 시작 - API 서버는 즉시 시작하여 /readyz로 진행 상황 노출
 	go k3sMgr.Start(ctx)
 	go apiServer.Start(ctx)
-	go suiIntegration.Start(ctx)
+
+	// Sui 이벤트는 kubectl로 실행되므로 K3s가 준비된 뒤 처리 시작
+	go func() {
+		select {
+		case <-k3sMgr.Ready():
+			suiIntegration.Start(ctx)
+		case <-ctx.Done():
+		}
+	}()
 
 	logger.Info("✅ All components started")
 
@@ -0,0 +1,212 @@
+// Maintenance Mode - 긴급 읽기 전용 유지보수 모드
+package main
+
+import (
+	"encoding/json"
+	"fmt"
+	"net/http"
+	"sync"
+	"time"
+
+	"github.com/sirupsen/logrus"
+)
+
+// MaintenanceMode - 활성화 시 K8s API 쓰기 요청 거절
+type MaintenanceMode struct {
+	logger     *logrus.Logger
+	audit      *AuditLogger
+	breakGlass map[string]bool // break-glass 허용 사용자 (authenticatedUser 값)
+	enabled    bool
+	reason     string
+	until      time.Time // 자동 해제 시각 (zero면 수동 해제)
+	mutex      sync.Mutex
+}
+
+// MaintenanceStatus - 유지보수 모드 상태
+type MaintenanceStatus struct {
+	Enabled bool   `json:"enabled"`
+	Reason  string `json:"reason,omitempty"`
+	Until   string `json:"until,omitempty"`
+}
+
+// NewMaintenanceMode - 새 유지보수 모드 생성
+func NewMaintenanceMode(logger *logrus.Logger) *MaintenanceMode {
+	return &MaintenanceMode{
+		logger:     logger,
+		breakGlass: breakGlassIdentities(),
+	}
+}
+
+// SetAuditLogger - break-glass 쓰기를 감사 로그로 전달 (API 서버 생성 시 연결)
+func (m *MaintenanceMode) SetAuditLogger(audit *AuditLogger) {
+	m.audit = audit
+}
+
+// Enter - 유지보수 모드 진입 (ttl이 0이면 수동 해제 전까지 유지)
+func (m *MaintenanceMode) Enter(reason string, ttl time.Duration) {
+	m.mutex.Lock()
+	defer m.mutex.Unlock()
+
+	m.enabled = true
+	m.reason = reason
+	m.until = time.Time{}
+	if ttl > 0 {
+		m.until = time.Now().Add(ttl)
+	}
+	m.logger.Warnf("🚧 Entering read-only maintenance mode: %s (ttl: %s)", reason, ttl)
+}
+
+// Exit - 유지보수 모드 해제
+func (m *MaintenanceMode) Exit() {
+	m.mutex.Lock()
+	defer m.mutex.Unlock()
+
+	if m.enabled {
+		m.logger.Info("✅ Leaving read-only maintenance mode")
+	}
+	m.clear()
+}
+
+// clear - 유지보수 상태 초기화 (mutex 보유 상태에서 호출)
+func (m *MaintenanceMode) clear() {
+	m.enabled = false
+	m.reason = ""
+	m.until = time.Time{}
+}
+
+// Status - 현재 상태 (만료된 경우 자동 해제)
+// 만료 확인과 해제를 한 번의 쓰기 잠금 안에서 수행하여 그 사이 다른 관리자의 Enter를 지우지 않음
+func (m *MaintenanceMode) Status() MaintenanceStatus {
+	m.mutex.Lock()
+	defer m.mutex.Unlock()
+
+	if m.enabled && !m.until.IsZero() && time.Now().After(m.until) {
+		m.logger.Info("⏰ Maintenance mode TTL expired")
+		m.clear()
+	}
+
+	status := MaintenanceStatus{Enabled: m.enabled, Reason: m.reason}
+	if m.enabled && !m.until.IsZero() {
+		status.Until = m.until.Format(time.RFC3339)
+	}
+	return status
+}
+
+// Active - 유지보수 모드 활성 여부
+func (m *MaintenanceMode) Active() bool {
+	return m.Status().Enabled
+}
+
+// Wrap - 유지보수 중 쓰기 요청 거절 (노드 하트비트와 허용된 사용자의 break-glass 요청은 통과)
+// 8080 프록시를 거친 요청에만 적용되며 6443에 직접 접속한 클라이언트의 쓰기는 막지 못함
+func (m *MaintenanceMode) Wrap(next http.Handler) http.Handler {
+	return http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		if !isMutatingRequest(r) || isHeartbeatWrite(r) {
+			next.ServeHTTP(w, r)
+			return
+		}
+
+		status := m.Status()
+		if !status.Enabled {
+			next.ServeHTTP(w, r)
+			return
+		}
+
+		if reason := r.Header.Get(breakGlassHeader); reason != "" {
+			r.Header.Del(breakGlassHeader)
+			user := authenticatedUser(r)
+			if !m.breakGlass[user] {
+				m.logger.Warnf("🚫 Rejected break-glass write from unauthorized identity %s", user)
+				http.Error(w, "Break-glass is not permitted for this identity", http.StatusForbidden)
+				return
+			}
+
+			if !m.audit.RecordBreakGlass(r, "maintenance-write", reason) {
+				m.logger.Errorf("❌ Rejected break-glass write from %s: audit event could not be recorded", user)
+				http.Error(w, "Break-glass requires audit logging, which is unavailable", http.StatusServiceUnavailable)
+				return
+			}
+
+			m.logger.WithFields(logrus.Fields{
+				"audit":  "maintenance-break-glass",
+				"user":   user,
+				"remote": r.RemoteAddr,
+				"verb":   r.Method,
+				"path":   r.URL.Path,
+				"reason": reason,
+			}).Warn("🔓 Break-glass write during maintenance mode")
+			next.ServeHTTP(w, r)
+			return
+		}
+
+		w.Header().Set("Retry-After", "60")
+		http.Error(w, fmt.Sprintf("Cluster is in read-only maintenance mode: %s", status.Reason),
+			http.StatusServiceUnavailable)
+	})
+}
+
+// isHeartbeatWrite - 노드 상태 갱신(nodes/{name}/status) 또는 노드 Lease 갱신 여부
+// (노드 삭제/taint, 리더 선출 Lease 변경은 유지보수 중 차단)
+func isHeartbeatWrite(r *http.Request) bool {
+	if r.Method != http.MethodPut && r.Method != http.MethodPatch {
+		return false
+	}
+	info, ok := parseK8sPath(r.URL.Path)
+	if !ok || info.Name == "" {
+		return false
+	}
+	switch {
+	case info.APIGroup == "" && info.Resource == "nodes":
+		return info.Subresource == "status"
+	case info.APIGroup == "coordination.k8s.io" && info.Resource == "leases":
+		return info.Namespace == "kube-node-lease" && info.Subresource == ""
+	}
+	return false
+}
+
+// handleMaintenance - 유지보수 모드 조회(GET, read 권한) 및 변경(POST, maintenance 권한) 관리자 API
+func (a *APIServer) handleMaintenance(w http.ResponseWriter, r *http.Request) {
+	scope := adminScopeRead
+	if r.Method == http.MethodPost {
+		scope = adminScopeMaintenance
+	}
+	admin, ok := a.authorizeAdmin(r, scope)
+	if !ok {
+		http.Error(w, "Forbidden", http.StatusForbidden)
+		return
+	}
+
+	maintenance := a.k3sMgr.maintenance
+
+	switch r.Method {
+	case http.MethodGet:
+	case http.MethodPost:
+		var request struct {
+			Enabled    bool   `json:"enabled"`
+			Reason     string `json:"reason"`
+			TTLSeconds int    `json:"ttl_seconds"`
+		}
+		if err := json.NewDecoder(r.Body).Decode(&request); err != nil {
+			http.Error(w, "Invalid request body", http.StatusBadRequest)
+			return
+		}
+
+		if request.Enabled {
+			if request.Reason == "" {
+				http.Error(w, "reason is required", http.StatusBadRequest)
+				return
+			}
+			a.logger.Infof("🔑 Maintenance mode enabled by admin %s", admin)
+			maintenance.Enter(request.Reason, time.Duration(request.TTLSeconds)*time.Second)
+		} else {
+			a.logger.Infof("🔑 Maintenance mode disabled by admin %s", admin)
+			maintenance.Exit()
+		}
+	default:
+		http.Error(w, "Method not allowed", http.StatusMethodNotAllowed)
+		return
+	}
+
+	w.Header().Set("Content-Type", "application/json")
+	json.NewEncoder(w).Encode(maintenance.Status())
+}
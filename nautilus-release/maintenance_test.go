@@ -0,0 +1,49 @@
+package main
+
+import (
+	"net/http/httptest"
+	"testing"
+	"time"
+
+	"github.com/sirupsen/logrus"
+)
+
+func TestIsHeartbeatWrite(t *testing.T) {
+	tests := []struct {
+		method string
+		path   string
+		want   bool
+	}{
+		{"PATCH", "/api/v1/nodes/node-1/status", true},
+		{"PUT", "/api/v1/nodes/node-1/status", true},
+		{"PUT", "/apis/coordination.k8s.io/v1/namespaces/kube-node-lease/leases/node-1", true},
+		{"PATCH", "/api/v1/nodes/node-1", false},
+		{"DELETE", "/api/v1/nodes/node-1", false},
+		{"DELETE", "/api/v1/nodes/node-1/status", false},
+		{"POST", "/apis/coordination.k8s.io/v1/namespaces/kube-node-lease/leases", false},
+		{"PUT", "/apis/coordination.k8s.io/v1/namespaces/kube-system/leases/kube-scheduler", false},
+		{"DELETE", "/apis/coordination.k8s.io/v1/namespaces/kube-node-lease/leases/node-1", false},
+	}
+
+	for _, tt := range tests {
+		r := httptest.NewRequest(tt.method, tt.path, nil)
+		if got := isHeartbeatWrite(r); got != tt.want {
+			t.Errorf("isHeartbeatWrite(%s %s) = %v, want %v", tt.method, tt.path, got, tt.want)
+		}
+	}
+}
+
+func TestMaintenanceTTLExpiry(t *testing.T) {
+	m := NewMaintenanceMode(logrus.New())
+
+	m.Enter("upgrade", time.Millisecond)
+	time.Sleep(5 * time.Millisecond)
+	if m.Status().Enabled {
+		t.Fatal("maintenance mode still enabled after its TTL expired")
+	}
+
+	m.Enter("incident", 0)
+	if status := m.Status(); !status.Enabled || status.Reason != "incident" {
+		t.Errorf("Status() = %+v, want enabled with reason incident", status)
+	}
+}
@@ -0,0 +1,185 @@
+// Memory Pressure Monitor - 엔클레이브 메모리 압박 시 API 요청 우선순위 기반 차단
+package main
+
+import (
+	"bufio"
+	"context"
+	"net/http"
+	"os"
+	"strconv"
+	"strings"
+	"sync"
+	"time"
+
+	"github.com/sirupsen/logrus"
+)
+
+// MemoryPressureLevel - 메모리 압박 단계
+type MemoryPressureLevel int
+
+const (
+	MemoryPressureNormal MemoryPressureLevel = iota
+	MemoryPressureHigh
+	MemoryPressureCritical
+)
+
+// String - 로그/응답용 단계 이름
+func (l MemoryPressureLevel) String() string {
+	switch l {
+	case MemoryPressureHigh:
+		return "High"
+	case MemoryPressureCritical:
+		return "Critical"
+	default:
+		return "Normal"
+	}
+}
+
+// largeCreateBytes - High 단계에서 차단하는 대용량 생성 요청 기준
+const largeCreateBytes = 1 << 20
+
+// MemoryPressureMonitor - /proc/meminfo 기반 메모리 압박 감시
+type MemoryPressureMonitor struct {
+	logger            *logrus.Logger
+	highPercent       float64
+	criticalPercent   float64
+	retryAfterSeconds int
+	level             MemoryPressureLevel
+	mutex             sync.RWMutex
+}
+
+// NewMemoryPressureMonitor - 새 메모리 압박 감시기 생성
+func NewMemoryPressureMonitor(logger *logrus.Logger) *MemoryPressureMonitor {
+	high, _ := strconv.ParseFloat(getEnvOrDefault("NAUTILUS_MEMORY_HIGH_PERCENT", "80"), 64)
+	critical, _ := strconv.ParseFloat(getEnvOrDefault("NAUTILUS_MEMORY_CRITICAL_PERCENT", "90"), 64)
+
+	return &MemoryPressureMonitor{
+		logger:            logger,
+		highPercent:       high,
+		criticalPercent:   critical,
+		retryAfterSeconds: 5,
+		level:             MemoryPressureNormal,
+	}
+}
+
+// Start - 주기적으로 메모리 사용률 확인
+func (m *MemoryPressureMonitor) Start(ctx context.Context) {
+	ticker := time.NewTicker(5 * time.Second)
+	defer ticker.Stop()
+
+	for {
+		select {
+		case <-ctx.Done():
+			return
+		case <-ticker.C:
+			m.refresh()
+		}
+	}
+}
+
+// Level - 현재 메모리 압박 단계
+func (m *MemoryPressureMonitor) Level() MemoryPressureLevel {
+	m.mutex.RLock()
+	defer m.mutex.RUnlock()
+	return m.level
+}
+
+// refresh - 메모리 사용률을 읽어 단계 갱신
+func (m *MemoryPressureMonitor) refresh() {
+	usedPercent, err := readMemoryUsedPercent()
+	if err != nil {
+		m.logger.Debugf("⚠️ Failed to read memory usage: %v", err)
+		return
+	}
+
+	level := MemoryPressureNormal
+	switch {
+	case usedPercent >= m.criticalPercent:
+		level = MemoryPressureCritical
+	case usedPercent >= m.highPercent:
+		level = MemoryPressureHigh
+	}
+
+	m.mutex.Lock()
+	previous := m.level
+	m.level = level
+	m.mutex.Unlock()
+
+	if level != previous {
+		m.logger.Warnf("🧠 Memory pressure %s → %s (%.1f%% used)", previous, level, usedPercent)
+	}
+}
+
+// Wrap - 메모리 압박 단계에 따라 저우선순위 요청을 429로 차단
+// (8080 프록시 경유 요청에만 적용, 6443으로 직접 들어오는 요청은 차단하지 않음)
+func (m *MemoryPressureMonitor) Wrap(next http.Handler) http.Handler {
+	return http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		level := m.Level()
+		if level == MemoryPressureNormal || isPriorityTraffic(r) || !shouldShed(r, level) {
+			next.ServeHTTP(w, r)
+			return
+		}
+
+		m.logger.Warnf("🚦 Shedding %s %s under %s memory pressure", r.Method, r.URL.Path, level)
+		w.Header().Set("Retry-After", strconv.Itoa(m.retryAfterSeconds))
+		http.Error(w, "Too many requests: enclave memory pressure "+level.String(), http.StatusTooManyRequests)
+	})
+}
+
+// isPriorityTraffic - 노드 하트비트(Lease), 노드 상태, 리더 선출 트래픽 여부
+func isPriorityTraffic(r *http.Request) bool {
+	return strings.HasPrefix(r.URL.Path, "/apis/coordination.k8s.io/") ||
+		strings.HasPrefix(r.URL.Path, "/api/v1/nodes/")
+}
+
+// shouldShed - 단계별 차단 대상 요청 판단
+func shouldShed(r *http.Request, level MemoryPressureLevel) bool {
+	if level == MemoryPressureCritical {
+		// Critical: watch를 제외한 모든 목록 조회와 쓰기 요청 차단
+		return r.Method != http.MethodGet || (isListRequest(r) && !isWatchRequest(r))
+	}
+
+	// High: 클러스터 전체 LIST(GET /api/v1/namespaces 포함)와 대용량 생성 요청만 차단
+	if r.Method == http.MethodGet {
+		info, ok := parseK8sPath(r.URL.Path)
+		return ok && info.IsList() && info.Namespace == "" && !isWatchRequest(r)
+	}
+	// 크기를 알 수 없는 요청(chunked, ContentLength -1)은 대용량으로 간주
+	return r.Method == http.MethodPost && (r.ContentLength > largeCreateBytes || r.ContentLength < 0)
+}
+
+// isListRequest - 컬렉션 조회 요청 여부 (/api/v1/pods, /apis/g/v/namespaces/ns/r 등)
+func isListRequest(r *http.Request) bool {
+	info, ok := parseK8sPath(r.URL.Path)
+	return ok && info.IsList()
+}
+
+// readMemoryUsedPercent - /proc/meminfo 기준 메모리 사용률(%)
+func readMemoryUsedPercent() (float64, error) {
+	file, err := os.Open("/proc/meminfo")
+	if err != nil {
+		return 0, err
+	}
+	defer file.Close()
+
+	values := map[string]float64{}
+	scanner := bufio.NewScanner(file)
+	for scanner.Scan() {
+		fields := strings.Fields(scanner.Text())
+		if len(fields) < 2 {
+			continue
+		}
+		if v, err := strconv.ParseFloat(fields[1], 64); err == nil {
+			values[strings.TrimSuffix(fields[0], ":")] = v
+		}
+	}
+	if err := scanner.Err(); err != nil {
+		return 0, err
+	}
+
+	total := values["MemTotal"]
+	if total == 0 {
+		return 0, os.ErrInvalid
+	}
+	return (total - values["MemAvailable"]) / total * 100, nil
+}
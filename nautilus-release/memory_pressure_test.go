@@ -0,0 +1,54 @@
+package main
+
+import (
+	"net/http/httptest"
+	"strings"
+	"testing"
+)
+
+func TestShouldShed(t *testing.T) {
+	largeBody := strings.Repeat("x", largeCreateBytes+1)
+
+	tests := []struct {
+		level  MemoryPressureLevel
+		method string
+		target string
+		body   string
+		want   bool
+	}{
+		{MemoryPressureHigh, "GET", "/api/v1/pods", "", true},
+		{MemoryPressureHigh, "GET", "/apis/apps/v1/deployments", "", true},
+		{MemoryPressureHigh, "GET", "/api/v1/namespaces", "", true},
+		{MemoryPressureHigh, "GET", "/api/v1/namespaces/default", "", false},
+		{MemoryPressureHigh, "GET", "/api/v1/namespaces/default/pods", "", false},
+		{MemoryPressureHigh, "GET", "/api/v1/namespaces/default/pods/web", "", false},
+		{MemoryPressureHigh, "GET", "/api/v1/pods?watch=true", "", false},
+		{MemoryPressureHigh, "GET", "/apis/apps/v1/watch/deployments", "", false},
+		{MemoryPressureHigh, "POST", "/api/v1/namespaces/default/configmaps", "{}", false},
+		{MemoryPressureHigh, "POST", "/api/v1/namespaces/default/configmaps", largeBody, true},
+		{MemoryPressureHigh, "PUT", "/api/v1/namespaces/default/configmaps/big", largeBody, false},
+		{MemoryPressureHigh, "DELETE", "/api/v1/namespaces/default/pods/web", "", false},
+
+		{MemoryPressureCritical, "GET", "/api/v1/pods", "", true},
+		{MemoryPressureCritical, "GET", "/api/v1/namespaces/default/pods", "", true},
+		{MemoryPressureCritical, "GET", "/api/v1/namespaces/default/pods/web", "", false},
+		{MemoryPressureCritical, "GET", "/api/v1/namespaces/default/pods?watch=true", "", false},
+		{MemoryPressureCritical, "GET", "/version", "", false},
+		{MemoryPressureCritical, "POST", "/api/v1/namespaces/default/configmaps", "{}", true},
+		{MemoryPressureCritical, "PATCH", "/api/v1/namespaces/default/configmaps/cm", "{}", true},
+		{MemoryPressureCritical, "DELETE", "/api/v1/namespaces/default/pods/web", "", true},
+	}
+
+	for _, tt := range tests {
+		r := httptest.NewRequest(tt.method, tt.target, strings.NewReader(tt.body))
+		if got := shouldShed(r, tt.level); got != tt.want {
+			t.Errorf("shouldShed(%s %s, %s) = %v, want %v", tt.method, tt.target, tt.level, got, tt.want)
+		}
+	}
+
+	chunked := httptest.NewRequest("POST", "/api/v1/namespaces/default/configmaps", strings.NewReader("{}"))
+	chunked.ContentLength = -1
+	if !shouldShed(chunked, MemoryPressureHigh) {
+		t.Error("shouldShed() did not shed a POST with unknown content length under High pressure")
+	}
+}
@@ -0,0 +1,231 @@
+// Metrics - Prometheus 텍스트 형식 /metrics (K8s API 프록시 요청 및 구성요소 상태)
+package main
+
+import (
+	"crypto/tls"
+	"fmt"
+	"io"
+	"net/http"
+	"path/filepath"
+	"sort"
+	"strconv"
+	"strings"
+	"sync"
+	"sync/atomic"
+	"time"
+)
+
+// requestDurationBuckets - 요청 지연 히스토그램 구간 (초)
+var requestDurationBuckets = []float64{0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1, 2.5, 5, 10}
+
+// requestHistogram - verb별 지연 히스토그램
+type requestHistogram struct {
+	buckets []uint64
+	count   uint64
+	sum     float64
+}
+
+// APIMetrics - K8s API 프록시 요청 지표
+type APIMetrics struct {
+	inFlight  int64
+	requests  map[string]uint64 // "verb|code" → 요청 수
+	durations map[string]*requestHistogram
+	mutex     sync.Mutex
+}
+
+// NewAPIMetrics - 새 API 지표 생성
+func NewAPIMetrics() *APIMetrics {
+	return &APIMetrics{
+		requests:  make(map[string]uint64),
+		durations: make(map[string]*requestHistogram),
+	}
+}
+
+// Wrap - 요청 수, 응답 코드, 지연 시간, 처리 중 요청 수 기록
+func (m *APIMetrics) Wrap(next http.Handler) http.Handler {
+	return http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		atomic.AddInt64(&m.inFlight, 1)
+		defer atomic.AddInt64(&m.inFlight, -1)
+
+		start := time.Now()
+		recorder := &statusRecorder{ResponseWriter: w, status: http.StatusOK}
+		next.ServeHTTP(recorder, r)
+
+		m.observe(metricVerb(r.Method), recorder.status, time.Since(start).Seconds())
+	})
+}
+
+// metricVerb - 표준 HTTP 메서드만 레이블로 사용 (임의 메서드로 시계열이 무한히 늘어나는 것 방지)
+func metricVerb(method string) string {
+	switch method {
+	case http.MethodGet, http.MethodPost, http.MethodPut, http.MethodPatch,
+		http.MethodDelete, http.MethodHead, http.MethodOptions:
+		return strings.ToLower(method)
+	}
+	return "other"
+}
+
+// observe - 요청 1건 기록
+func (m *APIMetrics) observe(verb string, code int, seconds float64) {
+	m.mutex.Lock()
+	defer m.mutex.Unlock()
+
+	m.requests[verb+"|"+strconv.Itoa(code)]++
+
+	histogram, exists := m.durations[verb]
+	if !exists {
+		histogram = &requestHistogram{buckets: make([]uint64, len(requestDurationBuckets))}
+		m.durations[verb] = histogram
+	}
+	for i, bound := range requestDurationBuckets {
+		if seconds <= bound {
+			histogram.buckets[i]++
+		}
+	}
+	histogram.count++
+	histogram.sum += seconds
+}
+
+// writeTo - 프록시 요청 지표 출력
+func (m *APIMetrics) writeTo(b *strings.Builder) {
+	m.mutex.Lock()
+	defer m.mutex.Unlock()
+
+	writeMetricHeader(b, "nautilus_apiserver_request_total", "counter", "Proxied K8s API requests by verb and response code.")
+	for _, key := range sortedKeys(m.requests) {
+		verb, code, _ := strings.Cut(key, "|")
+		fmt.Fprintf(b, "nautilus_apiserver_request_total{verb=%q,code=%q} %d\n", verb, code, m.requests[key])
+	}
+
+	writeMetricHeader(b, "nautilus_apiserver_request_duration_seconds", "histogram", "Proxied K8s API request latency by verb.")
+	for _, verb := range sortedKeys(m.durations) {
+		histogram := m.durations[verb]
+		for i, bound := range requestDurationBuckets {
+			fmt.Fprintf(b, "nautilus_apiserver_request_duration_seconds_bucket{verb=%q,le=%q} %d\n",
+				verb, strconv.FormatFloat(bound, 'g', -1, 64), histogram.buckets[i])
+		}
+		fmt.Fprintf(b, "nautilus_apiserver_request_duration_seconds_bucket{verb=%q,le=\"+Inf\"} %d\n", verb, histogram.count)
+		fmt.Fprintf(b, "nautilus_apiserver_request_duration_seconds_sum{verb=%q} %g\n", verb, histogram.sum)
+		fmt.Fprintf(b, "nautilus_apiserver_request_duration_seconds_count{verb=%q} %d\n", verb, histogram.count)
+	}
+
+	writeMetricHeader(b, "nautilus_apiserver_inflight_requests", "gauge", "Proxied K8s API requests currently being served.")
+	fmt.Fprintf(b, "nautilus_apiserver_inflight_requests %d\n", atomic.LoadInt64(&m.inFlight))
+}
+
+// handleMetrics - Prometheus 텍스트 형식 지표 (관리자 read 권한 필요)
+func (a *APIServer) handleMetrics(w http.ResponseWriter, r *http.Request) {
+	if !a.isAdminRequest(r) {
+		http.Error(w, "Forbidden", http.StatusForbidden)
+		return
+	}
+
+	var b strings.Builder
+	a.metrics.writeTo(&b)
+
+	ready := 0
+	select {
+	case <-a.k3sMgr.Ready():
+		ready = 1
+	default:
+	}
+	writeGauge(&b, "nautilus_k3s_ready", "Whether the k3s API server has become ready.", float64(ready))
+	writeGauge(&b, "nautilus_maintenance_enabled", "Whether read-only maintenance mode is active.",
+		boolToFloat(a.k3sMgr.maintenance.Active()))
+	writeGauge(&b, "nautilus_memory_pressure_level", "Memory pressure level (0=Normal, 1=High, 2=Critical).",
+		float64(a.memMonitor.Level()))
+	writeGauge(&b, "nautilus_trusted_clock_anchored", "Whether the trusted clock is anchored to a Sui checkpoint.",
+		boolToFloat(a.k3sMgr.trustedClock.IsAnchored()))
+
+	writeMetricHeader(&b, "nautilus_audit_events_dropped_total", "counter", "Audit events dropped because the buffer was full.")
+	fmt.Fprintf(&b, "nautilus_audit_events_dropped_total %d\n", atomic.LoadUint64(&a.audit.droppedTotal))
+
+	w.Header().Set("Content-Type", "text/plain; version=0.0.4; charset=utf-8")
+	fmt.Fprint(w, b.String())
+}
+
+// apiServerMetricsTimeout - 업스트림 kube-apiserver /metrics 조회 제한 시간
+const apiServerMetricsTimeout = 10 * time.Second
+
+// handleAPIServerMetrics - kube-apiserver /metrics 프록시 (apiserver_storage_objects 등 업스트림 지표)
+// (업스트림은 K3s admin 클라이언트 인증서로 조회하므로 관리자 read 권한 필요, 요청의 인증 헤더는 전달하지 않음)
+func (a *APIServer) handleAPIServerMetrics(w http.ResponseWriter, r *http.Request) {
+	if !a.isAdminRequest(r) {
+		http.Error(w, "Forbidden", http.StatusForbidden)
+		return
+	}
+	select {
+	case <-a.k3sMgr.Ready():
+	default:
+		http.Error(w, "K3s API server is not ready", http.StatusServiceUnavailable)
+		return
+	}
+
+	tlsDir := filepath.Join(a.k3sMgr.dataDir, "server", "tls")
+	cert, err := tls.LoadX509KeyPair(
+		filepath.Join(tlsDir, "client-admin.crt"),
+		filepath.Join(tlsDir, "client-admin.key"))
+	if err != nil {
+		a.logger.Errorf("❌ Failed to load K3s admin client certificate: %v", err)
+		http.Error(w, "Failed to fetch API server metrics", http.StatusBadGateway)
+		return
+	}
+	client := &http.Client{
+		Timeout: apiServerMetricsTimeout,
+		Transport: &http.Transport{
+			// TLS 검증 비활성화 (개발용, K8s API 프록시와 동일)
+			TLSClientConfig: &tls.Config{InsecureSkipVerify: true, Certificates: []tls.Certificate{cert}},
+		},
+	}
+
+	req, err := http.NewRequestWithContext(r.Context(), http.MethodGet, "https://127.0.0.1:6443/metrics", nil)
+	if err != nil {
+		http.Error(w, "Failed to fetch API server metrics", http.StatusInternalServerError)
+		return
+	}
+	resp, err := client.Do(req)
+	if err != nil {
+		a.logger.Warnf("⚠️ Failed to fetch API server metrics: %v", err)
+		http.Error(w, "Failed to fetch API server metrics", http.StatusBadGateway)
+		return
+	}
+	defer resp.Body.Close()
+	if resp.StatusCode != http.StatusOK {
+		a.logger.Warnf("⚠️ API server metrics returned HTTP %d", resp.StatusCode)
+		http.Error(w, "Failed to fetch API server metrics", http.StatusBadGateway)
+		return
+	}
+
+	if contentType := resp.Header.Get("Content-Type"); contentType != "" {
+		w.Header().Set("Content-Type", contentType)
+	}
+	io.Copy(w, resp.Body)
+}
+
+// writeMetricHeader - HELP/TYPE 주석 출력
+func writeMetricHeader(b *strings.Builder, name, metricType, help string) {
+	fmt.Fprintf(b, "# HELP %s %s\n# TYPE %s %s\n", name, help, name, metricType)
+}
+
+// writeGauge - 레이블 없는 gauge 출력
+func writeGauge(b *strings.Builder, name, help string, value float64) {
+	writeMetricHeader(b, name, "gauge", help)
+	fmt.Fprintf(b, "%s %g\n", name, value)
+}
+
+func boolToFloat(value bool) float64 {
+	if value {
+		return 1
+	}
+	return 0
+}
+
+// sortedKeys - 출력 순서 고정을 위한 정렬된 키 목록
+func sortedKeys[V any](m map[string]V) []string {
+	keys := make([]string, 0, len(m))
+	for key := range m {
+		keys = append(keys, key)
+	}
+	sort.Strings(keys)
+	return keys
+}
@@ -0,0 +1,27 @@
+package main
+
+import "testing"
+
+func TestMetricVerb(t *testing.T) {
+	tests := []struct {
+		method string
+		want   string
+	}{
+		{"GET", "get"},
+		{"POST", "post"},
+		{"PUT", "put"},
+		{"PATCH", "patch"},
+		{"DELETE", "delete"},
+		{"HEAD", "head"},
+		{"OPTIONS", "options"},
+		{"get", "other"},
+		{"TRACE", "other"},
+		{"RANDOM-12345", "other"},
+	}
+
+	for _, tt := range tests {
+		if got := metricVerb(tt.method); got != tt.want {
+			t.Errorf("metricVerb(%q) = %q, want %q", tt.method, got, tt.want)
+		}
+	}
+}
@@ -0,0 +1,98 @@
+// Request Info - K8s API 경로 파싱 (미들웨어 공통)
+package main
+
+import (
+	"net/http"
+	"strings"
+)
+
+// k8sRequestInfo - K8s API 리소스 경로 구성요소
+type k8sRequestInfo struct {
+	APIGroup    string // core(/api)는 빈 문자열
+	APIVersion  string
+	Namespace   string
+	Resource    string
+	Name        string
+	Subresource string
+	LegacyWatch bool // /api/v1/watch/... 형식
+}
+
+// IsList - 컬렉션 조회 경로 여부 (/api/v1/pods, /apis/g/v/namespaces/ns/r 등)
+func (i k8sRequestInfo) IsList() bool {
+	return i.Resource != "" && i.Name == ""
+}
+
+// parseK8sPath - /api, /apis 경로를 리소스 단위로 분해 (리소스 경로가 아니면 false)
+func parseK8sPath(path string) (k8sRequestInfo, bool) {
+	var info k8sRequestInfo
+	parts := strings.Split(strings.Trim(path, "/"), "/")
+
+	switch {
+	case len(parts) >= 2 && parts[0] == "api":
+		info.APIVersion = parts[1]
+		parts = parts[2:]
+	case len(parts) >= 3 && parts[0] == "apis":
+		info.APIGroup = parts[1]
+		info.APIVersion = parts[2]
+		parts = parts[3:]
+	default:
+		return info, false
+	}
+
+	if len(parts) >= 1 && parts[0] == "watch" {
+		info.LegacyWatch = true
+		parts = parts[1:]
+	}
+	if len(parts) == 0 {
+		return info, false
+	}
+
+	// /namespaces/{ns}/{resource}... (namespaces/{name}/status, finalize는 Namespace 자체의 서브리소스)
+	if parts[0] == "namespaces" && len(parts) >= 3 && parts[2] != "status" && parts[2] != "finalize" {
+		info.Namespace = parts[1]
+		parts = parts[2:]
+	}
+
+	info.Resource = parts[0]
+	if len(parts) >= 2 {
+		info.Name = parts[1]
+	}
+	if len(parts) >= 3 {
+		info.Subresource = parts[2]
+	}
+	if info.Resource == "namespaces" {
+		info.Namespace = info.Name
+	}
+	return info, true
+}
+
+// k8sVerb - kube-apiserver 인가/감사와 같은 요청 verb (리소스 경로가 아니면 소문자 HTTP 메서드)
+func k8sVerb(r *http.Request) string {
+	info, ok := parseK8sPath(r.URL.Path)
+	if !ok {
+		return strings.ToLower(r.Method)
+	}
+
+	switch r.Method {
+	case http.MethodGet, http.MethodHead:
+		if isWatchRequest(r) {
+			return "watch"
+		}
+		if info.IsList() {
+			return "list"
+		}
+		return "get"
+	case http.MethodPost:
+		return "create"
+	case http.MethodPut:
+		return "update"
+	case http.MethodPatch:
+		return "patch"
+	case http.MethodDelete:
+		if info.IsList() {
+			return "deletecollection"
+		}
+		return "delete"
+	}
+	return strings.ToLower(r.Method)
+}
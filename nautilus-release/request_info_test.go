@@ -0,0 +1,124 @@
+package main
+
+import (
+	"net/http/httptest"
+	"testing"
+)
+
+func TestParseK8sPath(t *testing.T) {
+	tests := []struct {
+		path string
+		ok   bool
+		want k8sRequestInfo
+	}{
+		{"/api", false, k8sRequestInfo{}},
+		{"/api/v1", false, k8sRequestInfo{APIVersion: "v1"}},
+		{"/apis/apps", false, k8sRequestInfo{}},
+		{"/healthz", false, k8sRequestInfo{}},
+		{"/api/v1/pods", true, k8sRequestInfo{APIVersion: "v1", Resource: "pods"}},
+		{"/api/v1/namespaces", true, k8sRequestInfo{APIVersion: "v1", Resource: "namespaces"}},
+		{"/api/v1/namespaces/default", true,
+			k8sRequestInfo{APIVersion: "v1", Namespace: "default", Resource: "namespaces", Name: "default"}},
+		{"/api/v1/namespaces/default/status", true,
+			k8sRequestInfo{APIVersion: "v1", Namespace: "default", Resource: "namespaces", Name: "default", Subresource: "status"}},
+		{"/api/v1/namespaces/default/pods", true,
+			k8sRequestInfo{APIVersion: "v1", Namespace: "default", Resource: "pods"}},
+		{"/api/v1/namespaces/default/pods/web/log", true,
+			k8sRequestInfo{APIVersion: "v1", Namespace: "default", Resource: "pods", Name: "web", Subresource: "log"}},
+		{"/api/v1/nodes/node-1/status", true,
+			k8sRequestInfo{APIVersion: "v1", Resource: "nodes", Name: "node-1", Subresource: "status"}},
+		{"/api/v1/watch/namespaces/default/secrets", true,
+			k8sRequestInfo{APIVersion: "v1", Namespace: "default", Resource: "secrets", LegacyWatch: true}},
+		{"/apis/apps/v1/deployments", true,
+			k8sRequestInfo{APIGroup: "apps", APIVersion: "v1", Resource: "deployments"}},
+		{"/apis/coordination.k8s.io/v1/namespaces/kube-node-lease/leases/node-1", true,
+			k8sRequestInfo{APIGroup: "coordination.k8s.io", APIVersion: "v1", Namespace: "kube-node-lease", Resource: "leases", Name: "node-1"}},
+	}
+
+	for _, tt := range tests {
+		got, ok := parseK8sPath(tt.path)
+		if ok != tt.ok || (ok && got != tt.want) {
+			t.Errorf("parseK8sPath(%q) = %+v, %v; want %+v, %v", tt.path, got, ok, tt.want, tt.ok)
+		}
+	}
+}
+
+func TestIsListRequest(t *testing.T) {
+	tests := []struct {
+		path string
+		want bool
+	}{
+		{"/api", false},
+		{"/api/v1", false},
+		{"/api/v1/pods", true},
+		{"/api/v1/namespaces", true},
+		{"/api/v1/namespaces/default", false},
+		{"/api/v1/namespaces/default/pods", true},
+		{"/api/v1/namespaces/default/pods/web", false},
+		{"/apis/apps/v1/namespaces/default/deployments", true},
+		{"/apis/apps/v1/namespaces/default/deployments/web/scale", false},
+		{"/version", false},
+	}
+
+	for _, tt := range tests {
+		r := httptest.NewRequest("GET", tt.path, nil)
+		if got := isListRequest(r); got != tt.want {
+			t.Errorf("isListRequest(%q) = %v, want %v", tt.path, got, tt.want)
+		}
+	}
+}
+
+func TestIsSecretRequest(t *testing.T) {
+	tests := []struct {
+		path string
+		want bool
+	}{
+		{"/api/v1/secrets", true},
+		{"/api/v1/namespaces/default/secrets", true},
+		{"/api/v1/namespaces/default/secrets/token", true},
+		{"/api/v1/watch/namespaces/default/secrets", true},
+		{"/api/v1/namespaces/secrets", false},
+		{"/api/v1/namespaces/default/configmaps", false},
+		{"/apis/example.io/v1/secrets", false},
+		{"/api/v2/secrets", false},
+	}
+
+	for _, tt := range tests {
+		r := httptest.NewRequest("GET", tt.path, nil)
+		if got := isSecretRequest(r); got != tt.want {
+			t.Errorf("isSecretRequest(%q) = %v, want %v", tt.path, got, tt.want)
+		}
+	}
+}
+
+func TestK8sVerb(t *testing.T) {
+	tests := []struct {
+		method string
+		target string
+		want   string
+	}{
+		{"GET", "/api/v1/namespaces/default/pods", "list"},
+		{"GET", "/api/v1/namespaces", "list"},
+		{"GET", "/api/v1/namespaces/default/pods/web", "get"},
+		{"GET", "/api/v1/namespaces/default", "get"},
+		{"GET", "/api/v1/namespaces/default/pods/web/log", "get"},
+		{"HEAD", "/api/v1/namespaces/default/pods/web", "get"},
+		{"GET", "/api/v1/namespaces/default/pods?watch=true", "watch"},
+		{"GET", "/api/v1/watch/namespaces/default/pods", "watch"},
+		{"POST", "/api/v1/namespaces/default/pods", "create"},
+		{"PUT", "/api/v1/namespaces/default/pods/web", "update"},
+		{"PATCH", "/api/v1/namespaces/default/pods/web", "patch"},
+		{"DELETE", "/api/v1/namespaces/default/pods/web", "delete"},
+		{"DELETE", "/api/v1/namespaces/default/pods", "deletecollection"},
+		{"GET", "/version", "get"},
+		{"GET", "/apis", "get"},
+		{"POST", "/openapi/v3", "post"},
+	}
+
+	for _, tt := range tests {
+		r := httptest.NewRequest(tt.method, tt.target, nil)
+		if got := k8sVerb(r); got != tt.want {
+			t.Errorf("k8sVerb(%s %s) = %q, want %q", tt.method, tt.target, got, tt.want)
+		}
+	}
+}
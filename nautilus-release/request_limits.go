@@ -0,0 +1,50 @@
+// Request Limits - K8s API 요청 본문 크기 제한
+package main
+
+import (
+	"fmt"
+	"net/http"
+	"strconv"
+
+	"github.com/sirupsen/logrus"
+)
+
+// defaultMaxRequestBytes - 기본 요청 본문 최대 크기 (kube-apiserver 기본값과 동일한 3MiB)
+const defaultMaxRequestBytes = 3 << 20
+
+// RequestSizeLimiter - 요청 본문을 버퍼링하기 전에 크기 제한
+type RequestSizeLimiter struct {
+	logger   *logrus.Logger
+	maxBytes int64
+}
+
+// NewRequestSizeLimiter - 새 요청 크기 제한기 생성
+func NewRequestSizeLimiter(logger *logrus.Logger) *RequestSizeLimiter {
+	maxBytes, err := strconv.ParseInt(getEnvOrDefault("NAUTILUS_MAX_REQUEST_BYTES", strconv.Itoa(defaultMaxRequestBytes)), 10, 64)
+	if err != nil || maxBytes <= 0 {
+		logger.Warnf("⚠️ Invalid NAUTILUS_MAX_REQUEST_BYTES, using default %d", defaultMaxRequestBytes)
+		maxBytes = defaultMaxRequestBytes
+	}
+
+	return &RequestSizeLimiter{
+		logger:   logger,
+		maxBytes: maxBytes,
+	}
+}
+
+// Wrap - Content-Length 초과 요청은 즉시 413, 길이 미상 요청은 읽는 도중 차단
+func (l *RequestSizeLimiter) Wrap(next http.Handler) http.Handler {
+	return http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		if r.ContentLength > l.maxBytes {
+			l.logger.Warnf("📦 Rejecting %s %s: body %d bytes exceeds limit %d",
+				r.Method, r.URL.Path, r.ContentLength, l.maxBytes)
+			http.Error(w, fmt.Sprintf("Request entity too large: limit is %d bytes", l.maxBytes),
+				http.StatusRequestEntityTooLarge)
+			return
+		}
+
+		// chunked 전송 등 길이를 모르는 요청은 제한 초과 시 프록시 전송이 실패함
+		r.Body = http.MaxBytesReader(w, r.Body, l.maxBytes)
+		next.ServeHTTP(w, r)
+	})
+}
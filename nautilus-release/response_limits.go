@@ -0,0 +1,272 @@
+// Response Limits - 요청별 응답 크기 제한 및 클라이언트별 사용량(상위 소비자) 집계
+package main
+
+import (
+	"context"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"io"
+	"net/http"
+	"sort"
+	"strconv"
+	"sync"
+	"sync/atomic"
+	"time"
+
+	"github.com/sirupsen/logrus"
+)
+
+// defaultMaxResponseBytes - 기본 요청별 응답 최대 크기 (watch, 로그 등 스트리밍 제외)
+const defaultMaxResponseBytes = 64 << 20
+
+// errResponseTooLarge - 응답이 요청별 한도를 초과함
+var errResponseTooLarge = errors.New("response exceeds per-request size limit")
+
+// responseAccountKey - 요청별 사용량 기록용 context 키
+type responseAccountKey struct{}
+
+// responseAccount - 처리 중인 요청 1건의 사용량
+type responseAccount struct {
+	bytes   int64
+	aborted atomic.Bool
+}
+
+// clientUsage - 클라이언트별 누적 사용량
+type clientUsage struct {
+	Client   string `json:"client"`
+	Requests uint64 `json:"requests"`
+	Bytes    int64  `json:"bytes"`
+	MaxBytes int64  `json:"max_bytes"`
+	Aborted  uint64 `json:"aborted"`
+	LastPath string `json:"last_path"`
+	lastSeen time.Time
+}
+
+// ResponseGuard - 응답 크기 제한 및 사용량 집계
+type ResponseGuard struct {
+	logger   *logrus.Logger
+	maxBytes int64
+	usage    map[string]*clientUsage
+	mutex    sync.Mutex
+}
+
+// NewResponseGuard - 새 응답 크기 제한기 생성
+func NewResponseGuard(logger *logrus.Logger) *ResponseGuard {
+	maxBytes, err := strconv.ParseInt(getEnvOrDefault("NAUTILUS_MAX_RESPONSE_BYTES", strconv.Itoa(defaultMaxResponseBytes)), 10, 64)
+	if err != nil || maxBytes <= 0 {
+		logger.Warnf("⚠️ Invalid NAUTILUS_MAX_RESPONSE_BYTES, using default %d", defaultMaxResponseBytes)
+		maxBytes = defaultMaxResponseBytes
+	}
+
+	return &ResponseGuard{
+		logger:   logger,
+		maxBytes: maxBytes,
+		usage:    make(map[string]*clientUsage),
+	}
+}
+
+// Start - 1시간 이상 요청이 없는 클라이언트 사용량 정리
+func (g *ResponseGuard) Start(ctx context.Context) {
+	ticker := time.NewTicker(10 * time.Minute)
+	defer ticker.Stop()
+
+	for {
+		select {
+		case <-ctx.Done():
+			return
+		case <-ticker.C:
+			g.cleanup()
+		}
+	}
+}
+
+// Wrap - 요청별 응답 바이트 집계
+func (g *ResponseGuard) Wrap(next http.Handler) http.Handler {
+	return http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		account := &responseAccount{}
+		counter := &countingResponseWriter{ResponseWriter: w, account: account}
+		ctx := context.WithValue(r.Context(), responseAccountKey{}, account)
+
+		// 한도 초과로 스트리밍 응답이 중단되면 ReverseProxy가 ErrAbortHandler로 패닉하므로 defer로 기록
+		defer g.record(clientIdentity(r), r.URL.Path, account)
+		next.ServeHTTP(counter, r.WithContext(ctx))
+	})
+}
+
+// ModifyResponse - 한도를 넘는 응답 차단 (Content-Length 초과는 즉시, 길이 미상은 읽는 도중)
+func (g *ResponseGuard) ModifyResponse(resp *http.Response) error {
+	account, ok := resp.Request.Context().Value(responseAccountKey{}).(*responseAccount)
+	if !ok || isStreamingRequest(resp.Request) || resp.StatusCode == http.StatusSwitchingProtocols {
+		return nil
+	}
+
+	if resp.ContentLength > g.maxBytes {
+		account.aborted.Store(true)
+		return errResponseTooLarge
+	}
+	if resp.ContentLength < 0 {
+		resp.Body = &limitedResponseBody{ReadCloser: resp.Body, remaining: g.maxBytes, account: account}
+	}
+	return nil
+}
+
+// HandleError - 요청/응답 크기 초과는 413, 그 외 프록시 오류는 502
+func (g *ResponseGuard) HandleError(w http.ResponseWriter, r *http.Request, err error) {
+	if account, ok := r.Context().Value(responseAccountKey{}).(*responseAccount); ok && account.aborted.Load() {
+		g.logger.Warnf("📦 Aborting %s %s from %s: response exceeds %d bytes",
+			r.Method, r.URL.Path, clientIdentity(r), g.maxBytes)
+		http.Error(w, fmt.Sprintf("Response too large: limit is %d bytes, use limit/continue to paginate", g.maxBytes),
+			http.StatusRequestEntityTooLarge)
+		return
+	}
+
+	// chunked 요청 본문이 RequestSizeLimiter의 MaxBytesReader 한도를 넘은 경우
+	var tooLarge *http.MaxBytesError
+	if errors.As(err, &tooLarge) {
+		http.Error(w, fmt.Sprintf("Request entity too large: limit is %d bytes", tooLarge.Limit),
+			http.StatusRequestEntityTooLarge)
+		return
+	}
+
+	g.logger.Errorf("❌ K8s API proxy error for %s %s: %v", r.Method, r.URL.Path, err)
+	w.WriteHeader(http.StatusBadGateway)
+}
+
+// record - 완료된 요청 사용량 누적
+func (g *ResponseGuard) record(client, path string, account *responseAccount) {
+	g.mutex.Lock()
+	defer g.mutex.Unlock()
+
+	usage, exists := g.usage[client]
+	if !exists {
+		usage = &clientUsage{Client: client}
+		g.usage[client] = usage
+	}
+	usage.Requests++
+	usage.Bytes += account.bytes
+	if account.bytes > usage.MaxBytes {
+		usage.MaxBytes = account.bytes
+	}
+	if account.aborted.Load() {
+		usage.Aborted++
+	}
+	usage.LastPath = path
+	usage.lastSeen = time.Now()
+}
+
+// TopOffenders - 응답 바이트 기준 상위 클라이언트
+func (g *ResponseGuard) TopOffenders(limit int) []clientUsage {
+	g.mutex.Lock()
+	defer g.mutex.Unlock()
+
+	offenders := make([]clientUsage, 0, len(g.usage))
+	for _, usage := range g.usage {
+		offenders = append(offenders, *usage)
+	}
+	sort.Slice(offenders, func(i, j int) bool {
+		if offenders[i].Aborted != offenders[j].Aborted {
+			return offenders[i].Aborted > offenders[j].Aborted
+		}
+		return offenders[i].Bytes > offenders[j].Bytes
+	})
+	if len(offenders) > limit {
+		offenders = offenders[:limit]
+	}
+	return offenders
+}
+
+// cleanup - 오래된 클라이언트 사용량 삭제
+func (g *ResponseGuard) cleanup() {
+	g.mutex.Lock()
+	defer g.mutex.Unlock()
+
+	for client, usage := range g.usage {
+		if time.Since(usage.lastSeen) > time.Hour {
+			delete(g.usage, client)
+		}
+	}
+}
+
+// handleTopOffenders - 응답 사용량 상위 클라이언트 관리자 API (GET, ?limit=N)
+func (a *APIServer) handleTopOffenders(w http.ResponseWriter, r *http.Request) {
+	if !a.isAdminRequest(r) {
+		http.Error(w, "Forbidden", http.StatusForbidden)
+		return
+	}
+	if r.Method != http.MethodGet {
+		http.Error(w, "Method not allowed", http.StatusMethodNotAllowed)
+		return
+	}
+
+	limit, err := strconv.Atoi(r.URL.Query().Get("limit"))
+	if err != nil || limit <= 0 {
+		limit = 10
+	}
+
+	w.Header().Set("Content-Type", "application/json")
+	json.NewEncoder(w).Encode(map[string]interface{}{
+		"max_response_bytes": a.respGuard.maxBytes,
+		"offenders":          a.respGuard.TopOffenders(limit),
+	})
+}
+
+// isStreamingRequest - 길이 제한 없이 계속 전송되는 요청 (watch, 로그 follow, exec/attach/port-forward/proxy)
+func isStreamingRequest(r *http.Request) bool {
+	if isWatchRequest(r) || r.URL.Query().Get("follow") == "true" {
+		return true
+	}
+	info, ok := parseK8sPath(r.URL.Path)
+	if !ok {
+		return false
+	}
+	switch info.Subresource {
+	case "log", "exec", "attach", "portforward", "proxy":
+		return true
+	}
+	return false
+}
+
+// countingResponseWriter - 클라이언트로 보낸 응답 바이트 집계
+type countingResponseWriter struct {
+	http.ResponseWriter
+	account *responseAccount
+}
+
+// Write - 응답 바이트 집계
+func (c *countingResponseWriter) Write(data []byte) (int, error) {
+	n, err := c.ResponseWriter.Write(data)
+	atomic.AddInt64(&c.account.bytes, int64(n))
+	return n, err
+}
+
+// Unwrap - http.ResponseController가 원래 ResponseWriter에 접근하도록 허용
+func (c *countingResponseWriter) Unwrap() http.ResponseWriter {
+	return c.ResponseWriter
+}
+
+// limitedResponseBody - 길이 미상 응답을 한도까지만 읽고 초과 시 오류 반환
+type limitedResponseBody struct {
+	io.ReadCloser
+	remaining int64
+	account   *responseAccount
+}
+
+// Read - 한도 초과 시 errResponseTooLarge
+func (l *limitedResponseBody) Read(p []byte) (int, error) {
+	if l.remaining <= 0 {
+		// 정확히 한도 크기인 응답은 허용
+		var probe [1]byte
+		if n, err := l.ReadCloser.Read(probe[:]); n == 0 && err == io.EOF {
+			return 0, io.EOF
+		}
+		l.account.aborted.Store(true)
+		return 0, errResponseTooLarge
+	}
+	if int64(len(p)) > l.remaining {
+		p = p[:l.remaining]
+	}
+	n, err := l.ReadCloser.Read(p)
+	l.remaining -= int64(n)
+	return n, err
+}
@@ -0,0 +1,30 @@
+package main
+
+import (
+	"net/http/httptest"
+	"testing"
+)
+
+func TestIsStreamingRequest(t *testing.T) {
+	tests := []struct {
+		target string
+		want   bool
+	}{
+		{"/api/v1/pods?watch=true", true},
+		{"/api/v1/watch/pods", true},
+		{"/api/v1/namespaces/default/pods/web/log?follow=true", true},
+		{"/api/v1/namespaces/default/pods/web/log", true},
+		{"/api/v1/namespaces/default/pods/web/exec?command=sh", true},
+		{"/api/v1/namespaces/default/services/web/proxy", true},
+		{"/api/v1/pods", false},
+		{"/api/v1/namespaces/default/pods/web", false},
+		{"/api/v1/namespaces/default/pods/web/status", false},
+	}
+
+	for _, tt := range tests {
+		r := httptest.NewRequest("GET", tt.target, nil)
+		if got := isStreamingRequest(r); got != tt.want {
+			t.Errorf("isStreamingRequest(%q) = %v, want %v", tt.target, got, tt.want)
+		}
+	}
+}
@@ -7,7 +7,6 @@ import (
 	"fmt"
 	"os"
 	"runtime"
-	"time"
 
 	"github.com/sirupsen/logrus"
 )
@@ -15,12 +14,14 @@ import (
 // SealTokenManager handles real Seal Token generation and validation
 type SealTokenManager struct {
 	logger *logrus.Logger
+	clock  *TrustedClock
 }
 
 // NewSealTokenManager creates a new seal token manager
-func NewSealTokenManager(logger *logrus.Logger) *SealTokenManager {
+func NewSealTokenManager(logger *logrus.Logger, clock *TrustedClock) *SealTokenManager {
 	return &SealTokenManager{
 		logger: logger,
+		clock:  clock,
 	}
 }
 
@@ -32,8 +33,8 @@ func (stm *SealTokenManager) GenerateRealSealToken(nodeID string, stakeAmount ui
 		return "", fmt.Errorf("failed to get hardware fingerprint: %v", err)
 	}
 
-	// Get current timestamp
-	timestamp := time.Now().Unix()
+	// Get current timestamp from the trusted clock
+	timestamp := stm.clock.Now().Unix()
 
 	// Create unique seed
 	seed := fmt.Sprintf("%s:%s:%d:%s:%d",
@@ -131,8 +132,13 @@ func getHostname() string {
 }
 
 // CreateWorkerCertificate creates a certificate for worker authentication
-func (stm *SealTokenManager) CreateWorkerCertificate(nodeID, sealToken string) map[string]interface{} {
-	timestamp := time.Now().Unix()
+// (refuses to issue while the trusted clock is unanchored, since valid_until would come from the host clock)
+func (stm *SealTokenManager) CreateWorkerCertificate(nodeID, sealToken string) (map[string]interface{}, error) {
+	now, err := stm.clock.TrustedNow()
+	if err != nil {
+		return nil, fmt.Errorf("cannot issue worker certificate: %v", err)
+	}
+	timestamp := now.Unix()
 
 	cert := map[string]interface{}{
 		"node_id":     nodeID,
@@ -144,10 +150,11 @@ func (stm *SealTokenManager) CreateWorkerCertificate(nodeID, sealToken string) m
 	}
 
 	stm.logger.Infof("📜 Worker certificate created for %s", nodeID)
-	return cert
+	return cert, nil
 }
 
 // VerifyWorkerCertificate verifies a worker certificate
+// (rejects while the trusted clock is unanchored rather than trusting the host clock for expiry)
 func (stm *SealTokenManager) VerifyWorkerCertificate(cert map[string]interface{}) bool {
 	// Check required fields
 	requiredFields := []string{"node_id", "seal_token", "issued_at", "valid_until"}
@@ -165,7 +172,12 @@ func (stm *SealTokenManager) VerifyWorkerCertificate(cert map[string]interface{}
 		return false
 	}
 
-	if time.Now().Unix() > validUntil {
+	now, err := stm.clock.TrustedNow()
+	if err != nil {
+		stm.logger.Warnf("❌ Cannot check certificate expiry: %v", err)
+		return false
+	}
+	if now.Unix() > validUntil {
 		stm.logger.Warnf("❌ Certificate expired")
 		return false
 	}
@@ -0,0 +1,233 @@
+// Secret Access Guard - Secret 데이터 마스킹 및 break-glass 감사 모드
+package main
+
+import (
+	"bytes"
+	"context"
+	"encoding/json"
+	"fmt"
+	"io"
+	"net/http"
+	"strconv"
+	"strings"
+
+	"github.com/sirupsen/logrus"
+)
+
+// breakGlassHeader - Secret 원본 데이터 조회 시 필요한 헤더 (값: 조회 사유)
+const breakGlassHeader = "X-Nautilus-Break-Glass"
+
+// secretRedactKey - 응답 마스킹 대상 요청 표시용 context 키
+type secretRedactKey struct{}
+
+// SecretAccessGuard - Secret 읽기 요청 보호
+type SecretAccessGuard struct {
+	logger     *logrus.Logger
+	audit      *AuditLogger
+	breakGlass map[string]bool // break-glass 허용 사용자 (authenticatedUser 값)
+	enabled    bool
+}
+
+// NewSecretAccessGuard - 새 Secret Access Guard 생성
+func NewSecretAccessGuard(logger *logrus.Logger, audit *AuditLogger) *SecretAccessGuard {
+	return &SecretAccessGuard{
+		logger:     logger,
+		audit:      audit,
+		breakGlass: breakGlassIdentities(),
+		enabled:    getEnvOrDefault("NAUTILUS_SECRET_REDACTION", "false") == "true",
+	}
+}
+
+// breakGlassIdentities - NAUTILUS_BREAK_GLASS_IDENTITIES (쉼표 구분) 허용 사용자 목록
+func breakGlassIdentities() map[string]bool {
+	identities := make(map[string]bool)
+	for _, identity := range strings.Split(getEnvOrDefault("NAUTILUS_BREAK_GLASS_IDENTITIES", ""), ",") {
+		if identity = strings.TrimSpace(identity); identity != "" {
+			identities[identity] = true
+		}
+	}
+	return identities
+}
+
+// Wrap - K8s 프록시 앞단에서 Secret 요청 검사 (쓰기 응답도 객체 전체를 반환하므로 함께 마스킹)
+func (g *SecretAccessGuard) Wrap(next http.Handler) http.Handler {
+	return http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		if !g.enabled || !isSecretRedactionMethod(r.Method) || !isSecretRequest(r) {
+			next.ServeHTTP(w, r)
+			return
+		}
+
+		reason := r.Header.Get(breakGlassHeader)
+		r.Header.Del(breakGlassHeader)
+
+		// Break-glass: 허용된 사용자만 원본 데이터 반환, 감사 이벤트를 기록하지 못하면 거절
+		if reason != "" {
+			user := authenticatedUser(r)
+			if !g.breakGlass[user] {
+				g.logger.Warnf("🚫 Rejected break-glass secret access from unauthorized identity %s", user)
+				http.Error(w, "Break-glass is not permitted for this identity", http.StatusForbidden)
+				return
+			}
+			if !g.audit.RecordBreakGlass(r, "secret-access", reason) {
+				g.logger.Errorf("❌ Rejected break-glass secret access from %s: audit event could not be recorded", user)
+				http.Error(w, "Break-glass requires audit logging, which is unavailable", http.StatusServiceUnavailable)
+				return
+			}
+
+			g.logger.WithFields(logrus.Fields{
+				"audit":  "secret-break-glass",
+				"user":   user,
+				"remote": r.RemoteAddr,
+				"verb":   r.Method,
+				"path":   r.URL.Path,
+				"reason": reason,
+			}).Warn("🔓 Break-glass secret access")
+			next.ServeHTTP(w, r)
+			return
+		}
+
+		// 마스킹할 수 없는 watch 스트림은 break-glass 없이 허용하지 않음
+		if isWatchRequest(r) {
+			http.Error(w, fmt.Sprintf("Secret watch requires %s header", breakGlassHeader), http.StatusForbidden)
+			return
+		}
+
+		// 응답 본문을 해석할 수 있도록 JSON(as=Table 등 파라미터 유지)/비압축 응답 요청
+		r.Header.Set("Accept", jsonOnlyAccept(r.Header.Get("Accept")))
+		r.Header.Del("Accept-Encoding")
+
+		ctx := context.WithValue(r.Context(), secretRedactKey{}, true)
+		next.ServeHTTP(w, r.WithContext(ctx))
+	})
+}
+
+// ModifyResponse - Secret 응답에서 data/stringData 제거 (메타데이터만 반환)
+func (g *SecretAccessGuard) ModifyResponse(resp *http.Response) error {
+	if redact, _ := resp.Request.Context().Value(secretRedactKey{}).(bool); !redact {
+		return nil
+	}
+	if resp.StatusCode < 200 || resp.StatusCode >= 300 {
+		return nil
+	}
+
+	body, err := io.ReadAll(resp.Body)
+	resp.Body.Close()
+	if err != nil {
+		return fmt.Errorf("failed to read secret response: %v", err)
+	}
+
+	redacted, err := redactSecretBody(body)
+	if err != nil {
+		return err
+	}
+
+	resp.Body = io.NopCloser(bytes.NewReader(redacted))
+	resp.ContentLength = int64(len(redacted))
+	resp.Header.Set("Content-Length", strconv.Itoa(len(redacted)))
+	return nil
+}
+
+// redactSecretBody - Secret 응답 본문의 데이터 제거
+// (Secret, PartialObjectMetadata와 그 목록, 행마다 객체를 포함한 Table, Status만 허용)
+func redactSecretBody(body []byte) ([]byte, error) {
+	var obj map[string]interface{}
+	if err := json.Unmarshal(body, &obj); err != nil {
+		// JSON이 아닌 응답은 데이터 노출 위험이 있으므로 차단
+		return nil, fmt.Errorf("failed to decode secret response: %v", err)
+	}
+
+	switch obj["kind"] {
+	case "Secret", "PartialObjectMetadata":
+		redactSecretObject(obj)
+	case "SecretList", "PartialObjectMetadataList":
+		if items, ok := obj["items"].([]interface{}); ok {
+			for _, item := range items {
+				if item, ok := item.(map[string]interface{}); ok {
+					redactSecretObject(item)
+				}
+			}
+		}
+	case "Table":
+		// kubectl get (as=Table) - includeObject=Object/Metadata이면 행마다 Secret 또는 메타데이터가 포함됨
+		if rows, ok := obj["rows"].([]interface{}); ok {
+			for _, row := range rows {
+				if row, ok := row.(map[string]interface{}); ok {
+					if object, ok := row["object"].(map[string]interface{}); ok {
+						redactSecretObject(object)
+					}
+				}
+			}
+		}
+	case "Status":
+	default:
+		// 형식을 모르는 응답은 데이터가 포함될 수 있으므로 차단
+		return nil, fmt.Errorf("unexpected secret response kind %v", obj["kind"])
+	}
+
+	redacted, err := json.Marshal(obj)
+	if err != nil {
+		return nil, fmt.Errorf("failed to encode secret response: %v", err)
+	}
+	return redacted, nil
+}
+
+// redactSecretObject - Secret 또는 PartialObjectMetadata 객체에서 데이터와 데이터가 담긴 메타데이터 제거
+func redactSecretObject(obj map[string]interface{}) {
+	delete(obj, "data")
+	delete(obj, "stringData")
+
+	metadata, ok := obj["metadata"].(map[string]interface{})
+	if !ok {
+		return
+	}
+	// managedFields에는 데이터 키 목록이 포함됨
+	delete(metadata, "managedFields")
+	annotations, ok := metadata["annotations"].(map[string]interface{})
+	if !ok {
+		annotations = map[string]interface{}{}
+		metadata["annotations"] = annotations
+	}
+	// kubectl apply(client-side)가 저장한 원본 매니페스트에도 데이터가 포함됨
+	delete(annotations, "kubectl.kubernetes.io/last-applied-configuration")
+	annotations["nautilus.io/redacted"] = "true"
+}
+
+// isSecretRedactionMethod - 응답에 Secret 객체가 포함되는 메서드 (조회 및 쓰기)
+func isSecretRedactionMethod(method string) bool {
+	switch method {
+	case http.MethodGet, http.MethodPost, http.MethodPut, http.MethodPatch, http.MethodDelete:
+		return true
+	}
+	return false
+}
+
+// jsonOnlyAccept - Accept 헤더에서 JSON 미디어 타입만 남김 (protobuf/YAML 제거, as=Table 등 파라미터 유지)
+func jsonOnlyAccept(accept string) string {
+	var kept []string
+	for _, mediaType := range strings.Split(accept, ",") {
+		mediaType = strings.TrimSpace(mediaType)
+		if strings.HasPrefix(mediaType, "application/json") {
+			kept = append(kept, mediaType)
+		}
+	}
+	if len(kept) == 0 {
+		return "application/json"
+	}
+	return strings.Join(kept, ",")
+}
+
+// isSecretRequest - core/v1 secrets 리소스 요청 여부
+func isSecretRequest(r *http.Request) bool {
+	info, ok := parseK8sPath(r.URL.Path)
+	return ok && info.APIGroup == "" && info.APIVersion == "v1" && info.Resource == "secrets"
+}
+
+// isWatchRequest - watch 스트림 요청 여부 (?watch=true 또는 /api/v1/watch/, /apis/{g}/{v}/watch/ 경로)
+func isWatchRequest(r *http.Request) bool {
+	watch := r.URL.Query().Get("watch")
+	if watch == "true" || watch == "1" {
+		return true
+	}
+	info, ok := parseK8sPath(r.URL.Path)
+	return ok && info.LegacyWatch
+}
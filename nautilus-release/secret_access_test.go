@@ -0,0 +1,163 @@
+package main
+
+import (
+	"net/http"
+	"net/http/httptest"
+	"testing"
+
+	"github.com/sirupsen/logrus"
+)
+
+func TestJSONOnlyAccept(t *testing.T) {
+	tests := []struct {
+		accept string
+		want   string
+	}{
+		{"", "application/json"},
+		{"application/vnd.kubernetes.protobuf", "application/json"},
+		{"application/vnd.kubernetes.protobuf, application/json", "application/json"},
+		{"application/yaml", "application/json"},
+		{
+			"application/json;as=Table;v=v1;g=meta.k8s.io,application/json;as=Table;v=v1beta1;g=meta.k8s.io,application/json",
+			"application/json;as=Table;v=v1;g=meta.k8s.io,application/json;as=Table;v=v1beta1;g=meta.k8s.io,application/json",
+		},
+		{
+			"application/vnd.kubernetes.protobuf;as=Table;v=v1;g=meta.k8s.io, application/json;as=Table;v=v1;g=meta.k8s.io",
+			"application/json;as=Table;v=v1;g=meta.k8s.io",
+		},
+	}
+
+	for _, tt := range tests {
+		if got := jsonOnlyAccept(tt.accept); got != tt.want {
+			t.Errorf("jsonOnlyAccept(%q) = %q, want %q", tt.accept, got, tt.want)
+		}
+	}
+}
+
+func TestRedactSecretBody(t *testing.T) {
+	tests := []struct {
+		name string
+		body string
+		want string
+	}{
+		{
+			"secret",
+			`{"kind":"Secret","metadata":{"name":"s"},"data":{"k":"dg=="}}`,
+			`{"kind":"Secret","metadata":{"annotations":{"nautilus.io/redacted":"true"},"name":"s"}}`,
+		},
+		{
+			"last-applied annotation",
+			`{"kind":"Secret","metadata":{"annotations":{"kubectl.kubernetes.io/last-applied-configuration":"{\"data\":{}}"}},"stringData":{"k":"v"}}`,
+			`{"kind":"Secret","metadata":{"annotations":{"nautilus.io/redacted":"true"}}}`,
+		},
+		{
+			"list",
+			`{"kind":"SecretList","items":[{"kind":"Secret","metadata":{},"data":{"k":"dg=="}}]}`,
+			`{"items":[{"kind":"Secret","metadata":{"annotations":{"nautilus.io/redacted":"true"}}}],"kind":"SecretList"}`,
+		},
+		{
+			"table with objects",
+			`{"kind":"Table","rows":[{"cells":["s"],"object":{"kind":"Secret","metadata":{},"data":{"k":"dg=="}}}]}`,
+			`{"kind":"Table","rows":[{"cells":["s"],"object":{"kind":"Secret","metadata":{"annotations":{"nautilus.io/redacted":"true"}}}}]}`,
+		},
+		{
+			"table with partial metadata",
+			`{"kind":"Table","rows":[{"cells":["s"],"object":{"kind":"PartialObjectMetadata","metadata":{"name":"s","annotations":{"kubectl.kubernetes.io/last-applied-configuration":"{\"data\":{\"k\":\"dg==\"}}"}}}}]}`,
+			`{"kind":"Table","rows":[{"cells":["s"],"object":{"kind":"PartialObjectMetadata","metadata":{"annotations":{"nautilus.io/redacted":"true"},"name":"s"}}}]}`,
+		},
+		{
+			"table without objects",
+			`{"kind":"Table","rows":[{"cells":["s"]}]}`,
+			`{"kind":"Table","rows":[{"cells":["s"]}]}`,
+		},
+		{
+			"partial metadata",
+			`{"kind":"PartialObjectMetadata","metadata":{"name":"s","annotations":{"kubectl.kubernetes.io/last-applied-configuration":"{\"data\":{\"k\":\"dg==\"}}","team":"a"}}}`,
+			`{"kind":"PartialObjectMetadata","metadata":{"annotations":{"nautilus.io/redacted":"true","team":"a"},"name":"s"}}`,
+		},
+		{
+			"partial metadata list with managed fields",
+			`{"kind":"PartialObjectMetadataList","items":[{"metadata":{"name":"s","managedFields":[{"manager":"kubectl","fieldsV1":{"f:data":{"f:k":{}}}}],"annotations":{"kubectl.kubernetes.io/last-applied-configuration":"{}"}}}]}`,
+			`{"items":[{"metadata":{"annotations":{"nautilus.io/redacted":"true"},"name":"s"}}],"kind":"PartialObjectMetadataList"}`,
+		},
+		{
+			"status",
+			`{"kind":"Status","status":"Success"}`,
+			`{"kind":"Status","status":"Success"}`,
+		},
+	}
+
+	for _, tt := range tests {
+		got, err := redactSecretBody([]byte(tt.body))
+		if err != nil {
+			t.Errorf("%s: unexpected error: %v", tt.name, err)
+			continue
+		}
+		if string(got) != tt.want {
+			t.Errorf("%s: redactSecretBody() = %s, want %s", tt.name, got, tt.want)
+		}
+	}
+
+	if _, err := redactSecretBody([]byte("k8s\x00protobuf")); err == nil {
+		t.Error("redactSecretBody() accepted a non-JSON body")
+	}
+	if _, err := redactSecretBody([]byte(`{"kind":"List","items":[{"data":{"k":"dg=="}}]}`)); err == nil {
+		t.Error("redactSecretBody() accepted an unknown kind")
+	}
+}
+
+func TestIsWatchRequest(t *testing.T) {
+	tests := []struct {
+		target string
+		want   bool
+	}{
+		{"/api/v1/namespaces/default/pods?watch=true", true},
+		{"/api/v1/namespaces/default/pods?watch=1", true},
+		{"/api/v1/namespaces/default/pods?watch=false", false},
+		{"/api/v1/namespaces/default/pods", false},
+		{"/api/v1/watch/namespaces/default/pods", true},
+		{"/apis/apps/v1/watch/deployments", true},
+		{"/apis/apps/v1/namespaces/default/deployments", false},
+		{"/api/v1/namespaces/default/pods/watch", false},
+	}
+
+	for _, tt := range tests {
+		r := httptest.NewRequest("GET", tt.target, nil)
+		if got := isWatchRequest(r); got != tt.want {
+			t.Errorf("isWatchRequest(%q) = %v, want %v", tt.target, got, tt.want)
+		}
+	}
+}
+
+func TestSecretBreakGlass(t *testing.T) {
+	tests := []struct {
+		name     string
+		user     string
+		audit    *AuditLogger
+		wantCode int
+	}{
+		{"allowed identity", "alice", &AuditLogger{logPath: "audit.log", events: make(chan *AuditEvent, 1)}, http.StatusOK},
+		{"unlisted identity", "mallory", &AuditLogger{logPath: "audit.log", events: make(chan *AuditEvent, 1)}, http.StatusForbidden},
+		{"audit disabled", "alice", &AuditLogger{events: make(chan *AuditEvent, 1)}, http.StatusServiceUnavailable},
+		{"audit buffer full", "alice", &AuditLogger{logPath: "audit.log", events: make(chan *AuditEvent)}, http.StatusServiceUnavailable},
+	}
+
+	for _, tt := range tests {
+		guard := &SecretAccessGuard{
+			logger:     logrus.New(),
+			audit:      tt.audit,
+			breakGlass: map[string]bool{"alice": true},
+			enabled:    true,
+		}
+		handler := guard.Wrap(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {}))
+
+		r := httptest.NewRequest("GET", "/api/v1/namespaces/default/secrets/token", nil)
+		r.Header.Set(remoteUserHeader, tt.user)
+		r.Header.Set(breakGlassHeader, "incident-42")
+		w := httptest.NewRecorder()
+		handler.ServeHTTP(w, r)
+		if w.Code != tt.wantCode {
+			t.Errorf("%s: status = %d, want %d", tt.name, w.Code, tt.wantCode)
+		}
+	}
+}
@@ -0,0 +1,112 @@
+// Snapshot - 관리자 API로 트리거하는 etcd 스냅샷
+package main
+
+import (
+	"bytes"
+	"context"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"net/http"
+	"os/exec"
+	"strings"
+	"time"
+)
+
+// snapshotTimeout - 스냅샷 1회 최대 소요 시간
+const snapshotTimeout = 5 * time.Minute
+
+// errSnapshotInProgress - 다른 스냅샷이 진행 중
+var errSnapshotInProgress = errors.New("another snapshot is in progress")
+
+// SaveSnapshot - k3s etcd-snapshot save 실행 (동시에 하나만, 결과 출력 반환)
+func (k *K3sManager) SaveSnapshot(ctx context.Context, name string) (string, error) {
+	if !k.snapshotMutex.TryLock() {
+		return "", errSnapshotInProgress
+	}
+	defer k.snapshotMutex.Unlock()
+
+	args := []string{"etcd-snapshot", "save", "--data-dir", k.dataDir}
+	if name != "" {
+		args = append(args, "--name", name)
+	}
+
+	ctx, cancel := context.WithTimeout(ctx, snapshotTimeout)
+	defer cancel()
+
+	cmd := exec.CommandContext(ctx, k3sBinaryPath, args...)
+	var output bytes.Buffer
+	cmd.Stdout = &output
+	cmd.Stderr = &output
+	if err := cmd.Run(); err != nil {
+		return output.String(), fmt.Errorf("etcd snapshot failed: %v", err)
+	}
+	return output.String(), nil
+}
+
+// handleSnapshot - etcd 스냅샷 생성 관리자 API (POST, snapshot 권한, {"name": "..."} 선택)
+func (a *APIServer) handleSnapshot(w http.ResponseWriter, r *http.Request) {
+	admin, ok := a.authorizeAdmin(r, adminScopeSnapshot)
+	if !ok {
+		http.Error(w, "Forbidden", http.StatusForbidden)
+		return
+	}
+	if r.Method != http.MethodPost {
+		http.Error(w, "Method not allowed", http.StatusMethodNotAllowed)
+		return
+	}
+
+	var request struct {
+		Name string `json:"name"`
+	}
+	if r.ContentLength != 0 {
+		if err := json.NewDecoder(r.Body).Decode(&request); err != nil {
+			http.Error(w, "Invalid request body", http.StatusBadRequest)
+			return
+		}
+	}
+	if !isValidSnapshotName(request.Name) {
+		http.Error(w, "name must be at most 63 characters of a-z, 0-9, '-' or '.'", http.StatusBadRequest)
+		return
+	}
+
+	select {
+	case <-a.k3sMgr.Ready():
+	default:
+		http.Error(w, "K3s is not ready", http.StatusServiceUnavailable)
+		return
+	}
+
+	a.logger.Infof("📸 Saving etcd snapshot %q requested by admin %s", request.Name, admin)
+	// 클라이언트 연결이 끊겨도 진행 중인 스냅샷은 완료
+	output, err := a.k3sMgr.SaveSnapshot(context.WithoutCancel(r.Context()), request.Name)
+	if errors.Is(err, errSnapshotInProgress) {
+		http.Error(w, "Conflict: "+err.Error(), http.StatusConflict)
+		return
+	}
+	if err != nil {
+		a.logger.Errorf("❌ %v: %s", err, output)
+		http.Error(w, "Failed to save etcd snapshot", http.StatusInternalServerError)
+		return
+	}
+
+	a.logger.Info("✅ etcd snapshot saved")
+	w.Header().Set("Content-Type", "application/json")
+	json.NewEncoder(w).Encode(map[string]interface{}{
+		"status": "success",
+		"output": strings.TrimSpace(output),
+	})
+}
+
+// isValidSnapshotName - 빈 이름(k3s 기본값) 또는 소문자/숫자/'-'/'.'로 된 63자 이하 이름
+func isValidSnapshotName(name string) bool {
+	if len(name) > 63 {
+		return false
+	}
+	for _, c := range name {
+		if (c < 'a' || c > 'z') && (c < '0' || c > '9') && c != '-' && c != '.' {
+			return false
+		}
+	}
+	return !strings.HasPrefix(name, "-")
+}
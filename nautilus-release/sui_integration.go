@@ -112,11 +112,11 @@ func (s *SuiIntegration) Start(ctx context.Context) {
 
 // startRealMode - 실제 Contract 연동 모드
 func (s *SuiIntegration) startRealMode(ctx context.Context) {
-	// HTTP API 폴링으로 이벤트 수집
-	go s.pollSuiEvents(ctx)
+	// HTTP API 폴링으로 이벤트 수집 (패닉 시 자동 재시작)
+	go superviseLoop(ctx, s.logger, "Sui event poller", s.pollSuiEvents)
 
-	// 이벤트 처리 고루틴 시작
-	go s.processContractEvents(ctx)
+	// 이벤트 처리 고루틴 시작 (패닉 시 자동 재시작)
+	go superviseLoop(ctx, s.logger, "Sui event processor", s.processContractEvents)
 
 	// 주기적 상태 체크
 	go s.periodicHealthCheck(ctx)
@@ -569,6 +569,13 @@ func (s *SuiIntegration) handleK8sAPIRequest(event *SuiContractEvent) {
 		request.Method, request.Resource, request.Namespace, assignedWorker)
 	s.logger.Infof("📦 Request ID: %s, Payload: %s", requestID, payload)
 
+	// 유지보수 모드에서는 조회 요청만 실행
+	if strings.ToUpper(request.Method) != "GET" && s.k3sMgr.maintenance.Active() {
+		s.logger.Warnf("🚧 Skipping %s request %s: cluster is in read-only maintenance mode",
+			request.Method, requestID)
+		return
+	}
+
 	// K3s가 실행 중인지 확인
 	if !s.isK3sActuallyRunning() {
 		s.logger.Warn("⚠️ K3s is not ready, queuing request")
@@ -0,0 +1,209 @@
+// Summary - 대시보드용 네임스페이스 워크로드 요약 엔드포인트
+package main
+
+import (
+	"bytes"
+	"context"
+	"encoding/json"
+	"net/http"
+	"os/exec"
+	"sort"
+	"strconv"
+	"time"
+)
+
+const (
+	// maxSummaryEvents - 요약에 포함할 최근 이벤트 수
+	maxSummaryEvents = 10
+	// summaryTimeout - kubectl 조회 제한 시간 (클라이언트가 끊으면 즉시 중단)
+	summaryTimeout = 30 * time.Second
+)
+
+// summaryObject - kubectl List 응답에서 요약에 필요한 필드
+type summaryObject struct {
+	Kind     string `json:"kind"`
+	Metadata struct {
+		Name string `json:"name"`
+	} `json:"metadata"`
+	Spec struct {
+		Replicas *int `json:"replicas"`
+	} `json:"spec"`
+	Status struct {
+		Phase             string `json:"phase"`
+		ReadyReplicas     int    `json:"readyReplicas"`
+		AvailableReplicas int    `json:"availableReplicas"`
+	} `json:"status"`
+	// Event 필드
+	Type           string `json:"type"`
+	Reason         string `json:"reason"`
+	Message        string `json:"message"`
+	LastTimestamp  string `json:"lastTimestamp"`
+	EventTime      string `json:"eventTime"`
+	InvolvedObject struct {
+		Kind string `json:"kind"`
+		Name string `json:"name"`
+	} `json:"involvedObject"`
+}
+
+// DeploymentSummary - 디플로이먼트 준비 상태 요약
+type DeploymentSummary struct {
+	Name      string `json:"name"`
+	Replicas  int    `json:"replicas"`
+	Ready     int    `json:"ready"`
+	Available int    `json:"available"`
+}
+
+// EventSummary - 최근 이벤트 요약
+type EventSummary struct {
+	Type      string `json:"type"`
+	Reason    string `json:"reason"`
+	Message   string `json:"message"`
+	Object    string `json:"object"`
+	Timestamp string `json:"timestamp"`
+}
+
+// NamespaceSummary - 네임스페이스 워크로드 요약
+type NamespaceSummary struct {
+	Namespace    string              `json:"namespace"`
+	Deployments  []DeploymentSummary `json:"deployments"`
+	PodsByPhase  map[string]int      `json:"pods_by_phase"`
+	RecentEvents []EventSummary      `json:"recent_events"`
+}
+
+// handleSummary - 디플로이먼트/파드/이벤트를 한 번에 요약하여 반환
+// (호출자의 자격 증명이 아니라 K3s 관리자 kubeconfig로 모든 네임스페이스를 조회하므로 관리자 read 권한 필요)
+func (a *APIServer) handleSummary(w http.ResponseWriter, r *http.Request) {
+	if !a.isAdminRequest(r) {
+		http.Error(w, "Forbidden", http.StatusForbidden)
+		return
+	}
+	if r.Method != http.MethodGet {
+		http.Error(w, "Method not allowed", http.StatusMethodNotAllowed)
+		return
+	}
+
+	// K8s 프록시 체인을 거치지 않는 LIST이므로 메모리 압박 시 직접 차단
+	if level := a.memMonitor.Level(); level != MemoryPressureNormal {
+		a.logger.Warnf("🚦 Shedding workload summary under %s memory pressure", level)
+		w.Header().Set("Retry-After", strconv.Itoa(a.memMonitor.retryAfterSeconds))
+		http.Error(w, "Too many requests: enclave memory pressure "+level.String(), http.StatusTooManyRequests)
+		return
+	}
+
+	namespace := r.URL.Query().Get("namespace")
+	if namespace == "" {
+		namespace = "default"
+	}
+	if !isValidNamespaceName(namespace) {
+		http.Error(w, "Invalid namespace: must be a DNS-1123 label", http.StatusBadRequest)
+		return
+	}
+
+	a.logger.Infof("📊 Building workload summary for namespace %s...", namespace)
+
+	ctx, cancel := context.WithTimeout(r.Context(), summaryTimeout)
+	defer cancel()
+
+	cmd := exec.CommandContext(ctx, "kubectl", "get", "deployments,pods,events",
+		"-n", namespace, "-o", "json", "--kubeconfig", a.k3sMgr.GetKubeconfig())
+	var stdout, stderr bytes.Buffer
+	cmd.Stdout = &stdout
+	cmd.Stderr = &stderr
+
+	if err := cmd.Run(); err != nil {
+		if ctx.Err() == context.DeadlineExceeded {
+			a.logger.Errorf("❌ Workload summary timed out after %v", summaryTimeout)
+			http.Error(w, "Timed out fetching workloads", http.StatusGatewayTimeout)
+			return
+		}
+		a.logger.Errorf("❌ Failed to fetch workloads: %v, stderr: %s", err, stderr.String())
+		http.Error(w, "Failed to fetch workloads", http.StatusBadGateway)
+		return
+	}
+
+	var list struct {
+		Items []summaryObject `json:"items"`
+	}
+	if err := json.Unmarshal(stdout.Bytes(), &list); err != nil {
+		a.logger.Errorf("❌ Failed to decode workloads: %v", err)
+		http.Error(w, "Internal server error", http.StatusInternalServerError)
+		return
+	}
+
+	summary := buildNamespaceSummary(namespace, list.Items)
+
+	w.Header().Set("Content-Type", "application/json")
+	json.NewEncoder(w).Encode(map[string]interface{}{
+		"status": "success",
+		"data":   summary,
+	})
+
+	a.logger.Info("✅ Workload summary returned successfully")
+}
+
+// isValidNamespaceName - DNS-1123 label (소문자/숫자/'-', 63자 이하, 영숫자로 시작하고 끝남)
+func isValidNamespaceName(name string) bool {
+	if name == "" || len(name) > 63 {
+		return false
+	}
+	for _, c := range name {
+		if (c < 'a' || c > 'z') && (c < '0' || c > '9') && c != '-' {
+			return false
+		}
+	}
+	return name[0] != '-' && name[len(name)-1] != '-'
+}
+
+// buildNamespaceSummary - 객체 목록을 종류별로 집계
+func buildNamespaceSummary(namespace string, items []summaryObject) *NamespaceSummary {
+	summary := &NamespaceSummary{
+		Namespace:    namespace,
+		Deployments:  []DeploymentSummary{},
+		PodsByPhase:  map[string]int{},
+		RecentEvents: []EventSummary{},
+	}
+
+	for _, item := range items {
+		switch item.Kind {
+		case "Deployment":
+			replicas := 1
+			if item.Spec.Replicas != nil {
+				replicas = *item.Spec.Replicas
+			}
+			summary.Deployments = append(summary.Deployments, DeploymentSummary{
+				Name:      item.Metadata.Name,
+				Replicas:  replicas,
+				Ready:     item.Status.ReadyReplicas,
+				Available: item.Status.AvailableReplicas,
+			})
+		case "Pod":
+			phase := item.Status.Phase
+			if phase == "" {
+				phase = "Unknown"
+			}
+			summary.PodsByPhase[phase]++
+		case "Event":
+			timestamp := item.LastTimestamp
+			if timestamp == "" {
+				timestamp = item.EventTime
+			}
+			summary.RecentEvents = append(summary.RecentEvents, EventSummary{
+				Type:      item.Type,
+				Reason:    item.Reason,
+				Message:   item.Message,
+				Object:    item.InvolvedObject.Kind + "/" + item.InvolvedObject.Name,
+				Timestamp: timestamp,
+			})
+		}
+	}
+
+	// RFC3339 타임스탬프는 문자열 정렬로 최신순 정렬 가능
+	sort.Slice(summary.RecentEvents, func(i, j int) bool {
+		return summary.RecentEvents[i].Timestamp > summary.RecentEvents[j].Timestamp
+	})
+	if len(summary.RecentEvents) > maxSummaryEvents {
+		summary.RecentEvents = summary.RecentEvents[:maxSummaryEvents]
+	}
+
+	return summary
+}
@@ -0,0 +1,33 @@
+package main
+
+import (
+	"strings"
+	"testing"
+)
+
+func TestIsValidNamespaceName(t *testing.T) {
+	tests := []struct {
+		name string
+		want bool
+	}{
+		{"default", true},
+		{"kube-system", true},
+		{"team1", true},
+		{"a", true},
+		{strings.Repeat("a", 63), true},
+		{"", false},
+		{strings.Repeat("a", 64), false},
+		{"-default", false},
+		{"default-", false},
+		{"Default", false},
+		{"team.a", false},
+		{"--all-namespaces", false},
+		{"default --kubeconfig=/tmp/x", false},
+	}
+
+	for _, tt := range tests {
+		if got := isValidNamespaceName(tt.name); got != tt.want {
+			t.Errorf("isValidNamespaceName(%q) = %v, want %v", tt.name, got, tt.want)
+		}
+	}
+}
@@ -0,0 +1,112 @@
+// Supervisor - 컴포넌트 장애 감지 및 지수 백오프 재시작
+package main
+
+import (
+	"context"
+	"fmt"
+	"runtime/debug"
+	"strconv"
+	"time"
+
+	"github.com/sirupsen/logrus"
+)
+
+const (
+	// stableRunDuration - 이 시간 이상 실행되면 연속 실패 횟수 초기화
+	stableRunDuration = 2 * time.Minute
+	// defaultMaxRestarts - 연속 재시작 한도 (초과 시 에스컬레이션)
+	defaultMaxRestarts = 5
+)
+
+// restartBackoff - 재시작 지연 시간 계산 (1s, 2s, 4s ... 최대 1m)
+type restartBackoff struct {
+	initial time.Duration
+	max     time.Duration
+	current time.Duration
+}
+
+// newRestartBackoff - 새 백오프 생성
+func newRestartBackoff() *restartBackoff {
+	return &restartBackoff{
+		initial: time.Second,
+		max:     time.Minute,
+	}
+}
+
+// Next - 다음 재시작 지연 시간
+func (b *restartBackoff) Next() time.Duration {
+	if b.current == 0 {
+		b.current = b.initial
+	} else if b.current < b.max {
+		b.current *= 2
+		if b.current > b.max {
+			b.current = b.max
+		}
+	}
+	return b.current
+}
+
+// Reset - 안정적으로 실행된 뒤 지연 시간 초기화
+func (b *restartBackoff) Reset() {
+	b.current = 0
+}
+
+// maxRestartsFromEnv - NAUTILUS_MAX_RESTARTS 환경변수 (기본 5회)
+func maxRestartsFromEnv() int {
+	maxRestarts, err := strconv.Atoi(getEnvOrDefault("NAUTILUS_MAX_RESTARTS", strconv.Itoa(defaultMaxRestarts)))
+	if err != nil || maxRestarts <= 0 {
+		return defaultMaxRestarts
+	}
+	return maxRestarts
+}
+
+// superviseLoop - 루프 고루틴이 패닉/종료하면 백오프 후 재시작, 한도 초과 시 중단
+func superviseLoop(ctx context.Context, logger *logrus.Logger, name string, loop func(ctx context.Context)) {
+	backoff := newRestartBackoff()
+	maxRestarts := maxRestartsFromEnv()
+	failures := 0
+
+	for {
+		startedAt := time.Now()
+		err := runRecovered(ctx, loop)
+		if ctx.Err() != nil {
+			return
+		}
+
+		if time.Since(startedAt) >= stableRunDuration {
+			backoff.Reset()
+			failures = 0
+		}
+		failures++
+
+		if err != nil {
+			logger.Errorf("💥 %s crashed: %v", name, err)
+		} else {
+			logger.Warnf("⚠️ %s exited unexpectedly", name)
+		}
+
+		if failures > maxRestarts {
+			logger.Errorf("🚨 %s failed %d times in a row, giving up - manual intervention required", name, failures)
+			return
+		}
+
+		delay := backoff.Next()
+		logger.Warnf("🔁 Restarting %s in %s (attempt %d/%d)", name, delay, failures, maxRestarts)
+		select {
+		case <-ctx.Done():
+			return
+		case <-time.After(delay):
+		}
+	}
+}
+
+// runRecovered - 패닉을 에러로 변환하여 실행
+func runRecovered(ctx context.Context, loop func(ctx context.Context)) (err error) {
+	defer func() {
+		if r := recover(); r != nil {
+			err = fmt.Errorf("panic: %v\n%s", r, debug.Stack())
+		}
+	}()
+	loop(ctx)
+	return nil
+}
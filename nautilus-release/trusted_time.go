@@ -0,0 +1,184 @@
+// Trusted Time - Sui 체크포인트 기반 신뢰 시간원 (호스트 시계 조작 대응)
+package main
+
+import (
+	"bytes"
+	"context"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"net/http"
+	"strconv"
+	"sync"
+	"time"
+
+	"github.com/sirupsen/logrus"
+)
+
+// trustedTimeSyncInterval - 신뢰 앵커 갱신 주기
+const trustedTimeSyncInterval = 5 * time.Minute
+
+// errClockNotAnchored - 아직 Sui 체크포인트 앵커를 얻지 못함 (호스트 시계를 신뢰할 수 없으므로 만료 판단 불가)
+var errClockNotAnchored = errors.New("trusted clock is not anchored to a Sui checkpoint yet")
+
+// TrustedClock - 신뢰 앵커 + 단조 시계로 현재 시각 계산
+type TrustedClock struct {
+	logger        *logrus.Logger
+	rpcURL        string
+	maxDrift      time.Duration
+	anchorTrusted time.Time // 체크포인트에서 얻은 신뢰 시각
+	anchorLocal   time.Time // 앵커 시점의 로컬 시각 (단조 시계 값 포함)
+	anchored      bool
+	mutex         sync.RWMutex
+}
+
+// NewTrustedClock - 새 신뢰 시계 생성
+func NewTrustedClock(logger *logrus.Logger) *TrustedClock {
+	maxDrift, err := time.ParseDuration(getEnvOrDefault("NAUTILUS_MAX_CLOCK_DRIFT", "30s"))
+	if err != nil {
+		maxDrift = 30 * time.Second
+	}
+
+	return &TrustedClock{
+		logger:   logger,
+		rpcURL:   getEnvOrDefault("SUI_RPC_URL", "https://fullnode.testnet.sui.io"),
+		maxDrift: maxDrift,
+	}
+}
+
+// Start - 시작 시 및 주기적으로 신뢰 앵커 동기화
+func (c *TrustedClock) Start(ctx context.Context) {
+	c.logger.Info("⏱️ Starting trusted time source...")
+	c.sync()
+
+	ticker := time.NewTicker(trustedTimeSyncInterval)
+	defer ticker.Stop()
+
+	for {
+		select {
+		case <-ctx.Done():
+			return
+		case <-ticker.C:
+			c.sync()
+		}
+	}
+}
+
+// Now - 표시/로그용 현재 시각 (앵커가 없으면 호스트 시계, 만료 판단에는 TrustedNow 사용)
+func (c *TrustedClock) Now() time.Time {
+	if now, err := c.TrustedNow(); err == nil {
+		return now
+	}
+	return time.Now()
+}
+
+// TrustedNow - 신뢰 시각 반환 (앵커 이후 경과 시간은 단조 시계로 계산, 앵커가 없으면 errClockNotAnchored)
+func (c *TrustedClock) TrustedNow() (time.Time, error) {
+	c.mutex.RLock()
+	defer c.mutex.RUnlock()
+
+	if !c.anchored {
+		return time.Time{}, errClockNotAnchored
+	}
+	return c.anchorTrusted.Add(time.Since(c.anchorLocal)), nil
+}
+
+// IsAnchored - 신뢰 앵커 확보 여부
+func (c *TrustedClock) IsAnchored() bool {
+	c.mutex.RLock()
+	defer c.mutex.RUnlock()
+	return c.anchored
+}
+
+// sync - 최신 체크포인트 시각으로 앵커 갱신 및 드리프트 검사
+func (c *TrustedClock) sync() {
+	checkpointTime, err := c.fetchCheckpointTime()
+	if err != nil {
+		c.logger.Warnf("⚠️ Failed to sync trusted time: %v", err)
+		return
+	}
+
+	local := time.Now()
+	previous := c.Now()
+
+	c.mutex.Lock()
+	wasAnchored := c.anchored
+	// 체크포인트 시각은 단조 증가해야 함 - 되돌아가는 앵커는 거부
+	if wasAnchored && checkpointTime.Before(previous.Add(-c.maxDrift)) {
+		c.mutex.Unlock()
+		c.logger.Errorf("🚨 Trusted time anchor moved backwards (%s < %s), ignoring",
+			checkpointTime.Format(time.RFC3339), previous.Format(time.RFC3339))
+		return
+	}
+	c.anchorTrusted = checkpointTime
+	c.anchorLocal = local
+	c.anchored = true
+	c.mutex.Unlock()
+
+	// 호스트 시스템 시계와 신뢰 시각 비교
+	drift := local.Sub(checkpointTime)
+	if drift < 0 {
+		drift = -drift
+	}
+	if drift > c.maxDrift {
+		c.logger.Errorf("🚨 Host clock drift %s exceeds %s - system time may be manipulated", drift, c.maxDrift)
+	} else {
+		c.logger.Debugf("⏱️ Trusted time synced (drift %s)", drift)
+	}
+}
+
+// fetchCheckpointTime - 최신 Sui 체크포인트의 타임스탬프 조회
+func (c *TrustedClock) fetchCheckpointTime() (time.Time, error) {
+	var sequence string
+	if err := c.callRPC("sui_getLatestCheckpointSequenceNumber", []interface{}{}, &sequence); err != nil {
+		return time.Time{}, err
+	}
+
+	var checkpoint struct {
+		TimestampMs string `json:"timestampMs"`
+	}
+	if err := c.callRPC("sui_getCheckpoint", []interface{}{sequence}, &checkpoint); err != nil {
+		return time.Time{}, err
+	}
+
+	ms, err := strconv.ParseInt(checkpoint.TimestampMs, 10, 64)
+	if err != nil {
+		return time.Time{}, fmt.Errorf("invalid checkpoint timestamp %q: %v", checkpoint.TimestampMs, err)
+	}
+	return time.UnixMilli(ms), nil
+}
+
+// callRPC - Sui JSON-RPC 호출
+func (c *TrustedClock) callRPC(method string, params []interface{}, result interface{}) error {
+	requestBody, err := json.Marshal(map[string]interface{}{
+		"jsonrpc": "2.0",
+		"id":      1,
+		"method":  method,
+		"params":  params,
+	})
+	if err != nil {
+		return fmt.Errorf("failed to marshal request: %v", err)
+	}
+
+	client := &http.Client{Timeout: 10 * time.Second}
+	resp, err := client.Post(c.rpcURL, "application/json", bytes.NewReader(requestBody))
+	if err != nil {
+		return fmt.Errorf("failed to call %s: %v", method, err)
+	}
+	defer resp.Body.Close()
+	if resp.StatusCode != http.StatusOK {
+		return fmt.Errorf("%s returned HTTP %d", method, resp.StatusCode)
+	}
+
+	var response struct {
+		Result json.RawMessage `json:"result"`
+		Error  interface{}     `json:"error"`
+	}
+	if err := json.NewDecoder(resp.Body).Decode(&response); err != nil {
+		return fmt.Errorf("failed to decode %s response: %v", method, err)
+	}
+	if response.Error != nil {
+		return fmt.Errorf("sui API error: %v", response.Error)
+	}
+	return json.Unmarshal(response.Result, result)
+}
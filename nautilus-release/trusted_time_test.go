@@ -0,0 +1,68 @@
+package main
+
+import (
+	"net/http"
+	"net/http/httptest"
+	"testing"
+	"time"
+
+	"github.com/sirupsen/logrus"
+)
+
+func TestTrustedNowRequiresAnchor(t *testing.T) {
+	clock := &TrustedClock{logger: logrus.New()}
+	if _, err := clock.TrustedNow(); err != errClockNotAnchored {
+		t.Errorf("TrustedNow() before anchoring: error = %v, want %v", err, errClockNotAnchored)
+	}
+
+	anchor := time.Date(2026, 1, 1, 0, 0, 0, 0, time.UTC)
+	clock.anchorTrusted = anchor
+	clock.anchorLocal = time.Now()
+	clock.anchored = true
+	now, err := clock.TrustedNow()
+	if err != nil {
+		t.Fatalf("TrustedNow() after anchoring: unexpected error: %v", err)
+	}
+	if now.Before(anchor) || now.Sub(anchor) > time.Minute {
+		t.Errorf("TrustedNow() = %v, want shortly after %v", now, anchor)
+	}
+}
+
+func TestVerifyWorkerCertificateRequiresAnchor(t *testing.T) {
+	clock := &TrustedClock{logger: logrus.New()}
+	stm := NewSealTokenManager(logrus.New(), clock)
+	cert := map[string]interface{}{
+		"node_id":     "worker-1",
+		"seal_token":  "token",
+		"issued_at":   int64(0),
+		"valid_until": time.Now().Add(time.Hour).Unix(),
+	}
+
+	if stm.VerifyWorkerCertificate(cert) {
+		t.Error("VerifyWorkerCertificate() accepted a certificate while the clock was unanchored")
+	}
+	if _, err := stm.CreateWorkerCertificate("worker-1", "token"); err == nil {
+		t.Error("CreateWorkerCertificate() issued a certificate while the clock was unanchored")
+	}
+
+	clock.anchorTrusted = time.Now()
+	clock.anchorLocal = time.Now()
+	clock.anchored = true
+	if !stm.VerifyWorkerCertificate(cert) {
+		t.Error("VerifyWorkerCertificate() rejected a valid certificate after anchoring")
+	}
+}
+
+func TestCallRPCRejectsHTTPErrors(t *testing.T) {
+	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		w.WriteHeader(http.StatusBadGateway)
+		w.Write([]byte(`{"jsonrpc":"2.0","id":1,"result":"12345"}`))
+	}))
+	defer server.Close()
+
+	clock := &TrustedClock{logger: logrus.New(), rpcURL: server.URL}
+	var sequence string
+	if err := clock.callRPC("sui_getLatestCheckpointSequenceNumber", []interface{}{}, &sequence); err == nil {
+		t.Errorf("callRPC() accepted an HTTP 502 response (result %q)", sequence)
+	}
+}
@@ -0,0 +1,179 @@
+// Config Validation - 시작 전 설정 검증 및 dry-run 준비 상태 보고
+package main
+
+import (
+	"crypto/tls"
+	"crypto/x509"
+	"fmt"
+	"os"
+	"strconv"
+	"time"
+
+	"github.com/sirupsen/logrus"
+)
+
+// ConfigReport - 설정 검증 결과 (Errors가 있으면 시작 불가)
+type ConfigReport struct {
+	Errors   []string
+	Warnings []string
+}
+
+// Ready - 시작 가능 여부
+func (r *ConfigReport) Ready() bool {
+	return len(r.Errors) == 0
+}
+
+func (r *ConfigReport) errorf(format string, args ...interface{}) {
+	r.Errors = append(r.Errors, fmt.Sprintf(format, args...))
+}
+
+func (r *ConfigReport) warnf(format string, args ...interface{}) {
+	r.Warnings = append(r.Warnings, fmt.Sprintf(format, args...))
+}
+
+// validateConfig - 포트를 열지 않고 환경변수 설정 일관성 검증
+func validateConfig() *ConfigReport {
+	report := &ConfigReport{}
+
+	// TLS 인증서
+	certFile := os.Getenv("NAUTILUS_TLS_CERT_FILE")
+	keyFile := os.Getenv("NAUTILUS_TLS_KEY_FILE")
+	tlsEnabled := certFile != "" || getEnvOrDefault("NAUTILUS_TLS_SELF_SIGNED", "false") == "true"
+	switch {
+	case (certFile == "") != (keyFile == ""):
+		report.errorf("NAUTILUS_TLS_CERT_FILE and NAUTILUS_TLS_KEY_FILE must be set together")
+	case certFile != "":
+		if _, err := tls.LoadX509KeyPair(certFile, keyFile); err != nil {
+			report.errorf("TLS key pair: %v", err)
+		}
+	case !tlsEnabled:
+		report.warnf("TLS is disabled, API server will serve plain HTTP")
+	}
+
+	// CA 번들
+	for _, env := range []string{"NAUTILUS_FRONT_PROXY_CA_FILE", "NAUTILUS_OIDC_CA_FILE"} {
+		if path := os.Getenv(env); path != "" {
+			validateCAFile(report, env, path)
+		}
+	}
+	// front-proxy 헤더 인증은 클라이언트 인증서로만 검증 가능
+	if os.Getenv("NAUTILUS_FRONT_PROXY_CA_FILE") != "" && !tlsEnabled {
+		report.errorf("NAUTILUS_FRONT_PROXY_CA_FILE requires TLS (NAUTILUS_TLS_CERT_FILE/NAUTILUS_TLS_KEY_FILE or NAUTILUS_TLS_SELF_SIGNED=true)")
+	}
+	if path := os.Getenv("NAUTILUS_TOKEN_WEBHOOK_CONFIG_FILE"); path != "" {
+		if _, err := os.Stat(path); err != nil {
+			report.errorf("NAUTILUS_TOKEN_WEBHOOK_CONFIG_FILE: %v", err)
+		}
+	}
+	if os.Getenv("NAUTILUS_OIDC_ISSUER_URL") != "" && os.Getenv("NAUTILUS_OIDC_CLIENT_ID") == "" {
+		report.errorf("NAUTILUS_OIDC_CLIENT_ID is required when NAUTILUS_OIDC_ISSUER_URL is set")
+	}
+
+	// 감사 정책
+	if path := os.Getenv("NAUTILUS_AUDIT_POLICY_FILE"); path != "" {
+		if _, err := loadAuditPolicy(path); err != nil {
+			report.errorf("%v", err)
+		}
+	}
+
+	// 숫자/기간 설정
+	for _, env := range []string{
+		"NAUTILUS_MAX_RESTARTS",
+		"NAUTILUS_WATCH_REAUTH_SECONDS",
+		"NAUTILUS_CHURN_MAX_WRITES",
+		"NAUTILUS_MAX_REQUEST_BYTES",
+		"NAUTILUS_MAX_RESPONSE_BYTES",
+	} {
+		if value := os.Getenv(env); value != "" {
+			if n, err := strconv.Atoi(value); err != nil || n <= 0 {
+				report.errorf("%s must be a positive integer, got %q", env, value)
+			}
+		}
+	}
+	for _, env := range []string{
+		"NAUTILUS_MAX_CLOCK_DRIFT",
+		"NAUTILUS_TOKEN_WEBHOOK_CACHE_TTL",
+		"NAUTILUS_IDEMPOTENCY_TTL",
+	} {
+		if value := os.Getenv(env); value != "" {
+			if _, err := time.ParseDuration(value); err != nil {
+				report.errorf("%s: %v", env, err)
+			}
+		}
+	}
+
+	// 메모리 압박 임계값
+	high, errHigh := strconv.ParseFloat(getEnvOrDefault("NAUTILUS_MEMORY_HIGH_PERCENT", "80"), 64)
+	critical, errCritical := strconv.ParseFloat(getEnvOrDefault("NAUTILUS_MEMORY_CRITICAL_PERCENT", "90"), 64)
+	if errHigh != nil || errCritical != nil {
+		report.errorf("memory pressure thresholds must be numbers")
+	} else if high <= 0 || high >= critical || critical > 100 {
+		report.errorf("memory pressure thresholds must satisfy 0 < high (%.0f) < critical (%.0f) <= 100", high, critical)
+	}
+
+	// K3s 바이너리 (없으면 시작 시 다운로드)
+	if _, err := os.Stat(k3sBinaryPath); err != nil {
+		report.warnf("K3s binary not found at %s, it will be downloaded on startup", k3sBinaryPath)
+	}
+
+	// admission 플러그인 (NodeRestriction은 누락 시 자동 추가)
+	if plugins := os.Getenv("NAUTILUS_ADMISSION_PLUGINS"); plugins != "" && !hasAdmissionPlugin(plugins, requiredAdmissionPlugin) {
+		report.warnf("NAUTILUS_ADMISSION_PLUGINS does not include %s, it will be enabled anyway", requiredAdmissionPlugin)
+	}
+	if hasAdmissionPlugin(os.Getenv("NAUTILUS_DISABLE_ADMISSION_PLUGINS"), requiredAdmissionPlugin) {
+		report.warnf("NAUTILUS_DISABLE_ADMISSION_PLUGINS includes %s, it cannot be disabled and will be ignored", requiredAdmissionPlugin)
+	}
+
+	// churn 허용 목록 (위조 가능한 User-Agent 대신 인증된 사용자명 사용)
+	if os.Getenv("NAUTILUS_CHURN_ALLOWLIST") != "" {
+		report.warnf("NAUTILUS_CHURN_ALLOWLIST (User-Agent prefixes) is no longer supported, use NAUTILUS_CHURN_ALLOWED_USERS")
+	}
+
+	// Secret break-glass (허용 사용자와 감사 백엔드가 모두 있어야 사용 가능)
+	if getEnvOrDefault("NAUTILUS_SECRET_REDACTION", "false") == "true" &&
+		(os.Getenv("NAUTILUS_BREAK_GLASS_IDENTITIES") == "" ||
+			(os.Getenv("NAUTILUS_AUDIT_LOG_PATH") == "" && os.Getenv("NAUTILUS_AUDIT_WEBHOOK_URL") == "")) {
+		report.warnf("secret break-glass needs NAUTILUS_BREAK_GLASS_IDENTITIES and an audit backend, it is unavailable")
+	}
+
+	// 관리자 API
+	if path := os.Getenv("NAUTILUS_ADMIN_TOKENS_FILE"); path != "" {
+		if _, err := loadAdminTokens(path); err != nil {
+			report.errorf("NAUTILUS_ADMIN_TOKENS_FILE: %v", err)
+		}
+	} else if os.Getenv("NAUTILUS_ADMIN_TOKEN") == "" {
+		report.warnf("NAUTILUS_ADMIN_TOKEN and NAUTILUS_ADMIN_TOKENS_FILE are not set, admin endpoints are disabled")
+	}
+	if (os.Getenv("NAUTILUS_ADMIN_TOKENS_FILE") != "" || os.Getenv("NAUTILUS_ADMIN_TOKEN") != "") && !tlsEnabled {
+		report.warnf("admin tokens are configured but TLS is disabled, admin endpoints will reject all requests")
+	}
+
+	return report
+}
+
+// validateCAFile - PEM CA 번들에 인증서가 하나 이상 있는지 확인
+func validateCAFile(report *ConfigReport, env, path string) {
+	caPEM, err := os.ReadFile(path)
+	if err != nil {
+		report.errorf("%s: %v", env, err)
+		return
+	}
+	if !x509.NewCertPool().AppendCertsFromPEM(caPEM) {
+		report.errorf("%s: no certificates found in %s", env, path)
+	}
+}
+
+// logConfigReport - 검증 결과 출력
+func logConfigReport(logger *logrus.Logger, report *ConfigReport) {
+	for _, warning := range report.Warnings {
+		logger.Warnf("⚠️ %s", warning)
+	}
+	for _, problem := range report.Errors {
+		logger.Errorf("❌ %s", problem)
+	}
+	if report.Ready() {
+		logger.Infof("✅ Configuration valid (%d warnings)", len(report.Warnings))
+	} else {
+		logger.Errorf("❌ Configuration invalid (%d errors, %d warnings)", len(report.Errors), len(report.Warnings))
+	}
+}
@@ -0,0 +1,46 @@
+// Watch Timeout - watch 최대 유지 시간 제한 (권한 회수 후 RBAC 재인가 유예 시간)
+package main
+
+import (
+	"net/http"
+	"strconv"
+)
+
+// defaultWatchReauthSeconds - 기본 watch 최대 유지 시간 (초)
+const defaultWatchReauthSeconds = 300
+
+// watchReauthSeconds - NAUTILUS_WATCH_REAUTH_SECONDS (watch가 재연결·재인가되기까지의 최대 시간)
+func watchReauthSeconds() int {
+	seconds, err := strconv.Atoi(getEnvOrDefault("NAUTILUS_WATCH_REAUTH_SECONDS", strconv.Itoa(defaultWatchReauthSeconds)))
+	if err != nil || seconds <= 0 {
+		return defaultWatchReauthSeconds
+	}
+	return seconds
+}
+
+// minRequestTimeoutArg - kube-apiserver min-request-timeout 값
+// (timeoutSeconds가 없는 watch는 [min, 2*min] 초 사이에서 종료되므로 절반으로 설정하여 최대값을 맞춤)
+func minRequestTimeoutArg() string {
+	return strconv.Itoa(max(1, watchReauthSeconds()/2))
+}
+
+// clampWatchTimeout - 클라이언트가 지정한 watch timeoutSeconds를 최대 유지 시간으로 제한
+// (8080 프록시 경유 요청만 해당, 6443 직접 접속 시에는 min-request-timeout 기반 서버 기본값만 적용)
+// (kube-apiserver는 클라이언트 값을 그대로 사용하므로 매우 긴 값으로 재인가를 피할 수 있음)
+func clampWatchTimeout(next http.Handler) http.Handler {
+	limit := watchReauthSeconds()
+
+	return http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		if !isWatchRequest(r) {
+			next.ServeHTTP(w, r)
+			return
+		}
+
+		query := r.URL.Query()
+		if timeout, err := strconv.Atoi(query.Get("timeoutSeconds")); err == nil && timeout > limit {
+			query.Set("timeoutSeconds", strconv.Itoa(limit))
+			r.URL.RawQuery = query.Encode()
+		}
+		next.ServeHTTP(w, r)
+	})
+}
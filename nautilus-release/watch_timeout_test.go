@@ -0,0 +1,32 @@
+package main
+
+import (
+	"net/http"
+	"net/http/httptest"
+	"testing"
+)
+
+func TestClampWatchTimeout(t *testing.T) {
+	t.Setenv("NAUTILUS_WATCH_REAUTH_SECONDS", "300")
+
+	tests := []struct {
+		target string
+		want   string
+	}{
+		{"/api/v1/pods?watch=1&timeoutSeconds=31536000", "300"},
+		{"/api/v1/pods?watch=true&timeoutSeconds=60", "60"},
+		{"/api/v1/pods?watch=true", ""},
+		{"/api/v1/pods?timeoutSeconds=31536000", "31536000"},
+	}
+
+	for _, tt := range tests {
+		var got string
+		handler := clampWatchTimeout(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+			got = r.URL.Query().Get("timeoutSeconds")
+		}))
+		handler.ServeHTTP(httptest.NewRecorder(), httptest.NewRequest("GET", tt.target, nil))
+		if got != tt.want {
+			t.Errorf("clampWatchTimeout(%q) timeoutSeconds = %q, want %q", tt.target, got, tt.want)
+		}
+	}
+}
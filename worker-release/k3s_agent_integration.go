@@ -195,6 +195,7 @@ func (manager *K3sAgentManager) startAgent() error {
 		"--container-runtime-endpoint=" + manager.getContainerRuntimeEndpoint(),
 		"--fail-swap-on=false",
 		"--cgroup-driver=systemd",
+		"--feature-gates=InPlacePodVerticalScaling=true",
 	}
 	for _, arg := range kubeletArgs {
 		args = append(args, "--kubelet-arg", arg)
@@ -1184,6 +1184,8 @@ func (k *Kubelet) Start() error {
 		"--data-dir", k.dataDir,
 		"--node-name", k.nodeID,
 		"--kubelet-arg", "fail-swap-on=false",
+		// 마스터와 동일하게 재시작 없는 파드 리소스 변경 활성화
+		"--kubelet-arg", "feature-gates=InPlacePodVerticalScaling=true",
 	}
 
 	log.Printf("🚀 K3s Agent 명령 실행: %s %s", k3sBinary, strings.Join(args, " "))
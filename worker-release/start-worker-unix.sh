@@ -30,4 +30,5 @@ exec k3s agent \
     --server "$MASTER_URL" \
     --token "$JOIN_TOKEN" \
     --node-name "$NODE_ID" \
-    --kubelet-arg "--hostname-override=$NODE_ID"
\ No newline at end of file
+    --kubelet-arg "--hostname-override=$NODE_ID" \
+    --kubelet-arg "--feature-gates=InPlacePodVerticalScaling=true"
\ No newline at end of file